@@ -0,0 +1,15 @@
+//! Helpers shared by the whitebox tests of the multisig and its modules.
+
+use multiversx_sc::types::Address;
+
+const SC_ADDR_LEADING_ZEROES: usize = 8;
+
+/// Same format as the "sc:" scenario addresses, so all test contracts end up in the same shard,
+/// as required for transfer & execute and for modules.
+pub fn sc_address(name: &[u8]) -> Address {
+    let mut address = [b'_'; 32];
+    address[..SC_ADDR_LEADING_ZEROES].fill(0);
+    address[SC_ADDR_LEADING_ZEROES..SC_ADDR_LEADING_ZEROES + name.len()].copy_from_slice(name);
+
+    Address::from(address)
+}
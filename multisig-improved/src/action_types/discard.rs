@@ -25,6 +25,24 @@ pub trait DiscardActionModule:
         self.clear_action(action_id);
     }
 
+    fn discard_expired_action(&self, action_id: ActionId) {
+        self.require_action_exists(action_id);
+        require!(self.is_action_expired(action_id), "action not expired");
+
+        self.abort_batch_of_action(action_id);
+        self.clear_action(action_id);
+    }
+
+    fn veto_action(&self, action_id: ActionId, guardian: &ManagedAddress) {
+        self.require_action_exists(action_id);
+
+        let group_id = self.group_for_action(action_id).get();
+        self.abort_batch_of_action(action_id);
+        self.clear_action(action_id);
+
+        self.action_vetoed_event(action_id, group_id, guardian);
+    }
+
     fn abort_batch_of_action(&self, action_id: ActionId) {
         let batch_id = self.group_for_action(action_id).get();
         if batch_id != 0 {
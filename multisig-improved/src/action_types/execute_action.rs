@@ -1,18 +1,40 @@
 use crate::common_types::{
-    action::{Action, ActionId, CallActionData, DeployArgs, EsdtTransferExecuteData, GasLimit},
+    action::{
+        Action, ActionId, ActionKind, CallActionData, DeployArgs, EsdtTransferExecuteData,
+        GasLimit, GroupId, PromiseCallStatus, Timestamp, Weight,
+    },
     user_role::{change_user_role, UserRole},
 };
 
+use crate::common_functions::DEFAULT_WEIGHT;
 use crate::ms_endpoints::callbacks::CallbackProxy as _;
 
 multiversx_sc::imports!();
 
 /// Gas required to finish transaction after transfer-execute.
 const PERFORM_ACTION_FINISH_GAS: u64 = 300_000;
-pub const MAX_BOARD_MEMBERS: usize = 30;
-pub const MAX_MODULES: usize = 5;
+/// Gas reserved for recording the result of a promise call.
+pub const PROMISE_CALLBACK_GAS: GasLimit = 5_000_000;
+pub const DEFAULT_MAX_BOARD_MEMBERS: usize = 30;
+pub const DEFAULT_MAX_MODULES: usize = 5;
+/// Upper bound for the configurable board size.
+/// Finishing a recovery goes through the current board members, which are indexed separately from the other users,
+/// so its cost only depends on the board size. The board views are bounded the same way.
+/// The signers of an action are board members as well, except for the ones removed after signing.
+pub const MAX_BOARD_MEMBERS_LIMIT: usize = 100;
+/// Upper bound for the configurable module count, so that calling all modules still fits in the gas limit of a transaction.
+pub const MAX_MODULES_LIMIT: usize = 10;
+pub const MAX_ASYNC_CALL_RETRIES_LIMIT: usize = 10;
+pub const MAX_EXECUTION_DELAY: Timestamp = 30 * 24 * 60 * 60; // 30 days
+pub const MAX_BOARD_MEMBER_WEIGHT: Weight = 1_000_000;
+pub const MAX_RECOVERY_ADDRESSES: usize = 10;
+pub const MIN_RECOVERY_CHALLENGE_PERIOD: Timestamp = 7 * 24 * 60 * 60; // 7 days
+pub const MAX_RECOVERY_CHALLENGE_PERIOD: Timestamp = 365 * 24 * 60 * 60; // 1 year
 
 pub static BOARD_SIZE_TOO_BIG_ERR_MSG: &[u8] = b"board size cannot exceed limit";
+pub static QUORUM_TOO_BIG_ERR_MSG: &[u8] = b"quorum cannot exceed total board weight";
+pub static PROMISE_CALL_GAS_LIMIT_REQUIRED_ERR_MSG: &[u8] = b"gas limit required for promise calls";
+static EXECUTION_DELAY_TOO_BIG_ERR_MSG: &[u8] = b"execution delay too big";
 
 #[multiversx_sc::module]
 pub trait ExecuteActionModule:
@@ -36,22 +58,100 @@ pub trait ExecuteActionModule:
         OptionalValue::None
     }
 
-    fn execute_action_by_type(&self, action_id: ActionId, action: Action<Self::Api>) {
+    fn execute_action_by_type(
+        &self,
+        action_id: ActionId,
+        group_id: GroupId,
+        action: Action<Self::Api>,
+    ) {
         match action {
             Action::Nothing => {}
             Action::AddBoardMember(board_member_address) => {
                 self.add_board_member(action_id, board_member_address);
             }
             Action::AddProposer(proposer_address) => self.add_proposer(action_id, proposer_address),
+            Action::AddGuardian(guardian_address) => self.add_guardian(action_id, guardian_address),
+            Action::AddExecutor(executor_address) => self.add_executor(action_id, executor_address),
+            Action::Unpause => self.unpause(action_id),
+            Action::SetRecoveryConfig {
+                recovery_addresses,
+                challenge_period,
+            } => self.set_recovery_config(action_id, recovery_addresses, challenge_period),
+            Action::ReplaceBoardMember {
+                old_board_member,
+                new_board_member,
+                migrate_signatures,
+            } => self.replace_board_member(
+                action_id,
+                old_board_member,
+                new_board_member,
+                migrate_signatures,
+            ),
+            Action::ReconfigureBoard {
+                add,
+                remove,
+                proposers_add,
+                proposers_remove,
+                new_quorum,
+            } => self.reconfigure_board(
+                action_id,
+                add,
+                remove,
+                proposers_add,
+                proposers_remove,
+                new_quorum,
+            ),
             Action::RemoveUser(user_address) => self.remove_user(action_id, user_address),
+            Action::RemoveProposer(proposer_address) => {
+                self.remove_proposer(action_id, proposer_address);
+            }
+            Action::DemoteToProposer(board_member_address) => {
+                self.demote_to_proposer(action_id, board_member_address);
+            }
+            Action::PromoteToBoardMember(proposer_address) => {
+                self.promote_to_board_member(action_id, proposer_address);
+            }
             Action::ChangeQuorum(new_quorum) => self.change_quorum(action_id, new_quorum),
             Action::AddModule(sc_address) => self.add_module(action_id, sc_address),
             Action::RemoveModule(sc_address) => self.remove_module(action_id, sc_address),
-            _ => self.execute_external_call(action_id, action),
+            Action::SetExecutionDelay(delay) => self.set_execution_delay(action_id, delay),
+            Action::SetMaxBoardMembers(max_board_members) => {
+                self.set_max_board_members(action_id, max_board_members);
+            }
+            Action::SetMaxModules(max_modules) => self.set_max_modules(action_id, max_modules),
+            Action::SetMaxAsyncCallRetries(max_retries) => {
+                self.set_max_async_call_retries(action_id, max_retries);
+            }
+            Action::SetActionKindExecutionDelay {
+                action_kind,
+                opt_delay,
+            } => self.set_action_kind_execution_delay(action_id, action_kind, opt_delay),
+            Action::SetBoardMemberWeight {
+                board_member,
+                weight,
+            } => self.set_board_member_weight(action_id, board_member, weight),
+            Action::ChangeActionKindQuorum {
+                action_kind,
+                opt_quorum,
+            } => self.change_action_kind_quorum(action_id, action_kind, opt_quorum),
+            Action::SetModuleNotifications {
+                sc_address,
+                enabled,
+            } => self.set_module_notifications(action_id, sc_address, enabled),
+            Action::SetModuleActionApproval {
+                sc_address,
+                enabled,
+            } => self.set_module_action_approval(action_id, sc_address, enabled),
+            _ => self.execute_external_call(action_id, group_id, action),
         };
     }
 
-    fn execute_external_call(&self, action_id: ActionId, action: Action<Self::Api>) {
+    fn execute_external_call(
+        &self,
+        action_id: ActionId,
+        group_id: GroupId,
+        action: Action<Self::Api>,
+    ) {
         match action {
             Action::SendTransferExecuteEgld(call_data) => {
                 self.send_transfer_execute_egld(action_id, call_data);
@@ -60,18 +160,32 @@ pub trait ExecuteActionModule:
                 self.send_transfer_execute_esdt(action_id, call_data);
             }
             Action::SendAsyncCall(call_data) => {
-                self.send_async_call(action_id, call_data);
+                self.send_async_call(action_id, group_id, call_data);
+            }
+            Action::SendPromiseCallEgld(call_data) => {
+                self.send_promise_call_egld(action_id, group_id, call_data);
+            }
+            Action::SendPromiseCallEsdt(call_data) => {
+                self.send_promise_call_esdt(action_id, group_id, call_data);
             }
             Action::SCUpgradeFromSource { sc_address, args } => {
-                self.upgrade_from_source(action_id, sc_address, args);
+                self.upgrade_from_source(action_id, group_id, sc_address, args);
+            }
+            Action::ChangeOwner(new_owner) => {
+                let own_address = self.blockchain().get_sc_address();
+                self.change_owner(action_id, own_address, new_owner);
             }
+            Action::ChangeManagedContractOwner {
+                sc_address,
+                new_owner,
+            } => self.change_owner(action_id, sc_address, new_owner),
             _ => {} // Deploy case handled in "try_execute_deploy" function
         }
     }
 
     fn add_board_member(&self, action_id: ActionId, board_member_address: ManagedAddress) {
         require!(
-            self.num_board_members().get() < MAX_BOARD_MEMBERS,
+            self.num_board_members().get() < self.max_board_members().get(),
             BOARD_SIZE_TOO_BIG_ERR_MSG
         );
 
@@ -82,9 +196,144 @@ pub trait ExecuteActionModule:
         change_user_role(self, action_id, proposer_address, UserRole::Proposer);
 
         // validation required for the scenario when a board member becomes a proposer
-        let quorum = self.quorum().get();
-        let board_members = self.num_board_members().get();
-        self.require_valid_quorum(quorum, board_members);
+        self.require_valid_quorums();
+    }
+
+    fn add_guardian(&self, action_id: ActionId, guardian_address: ManagedAddress) {
+        change_user_role(self, action_id, guardian_address, UserRole::Guardian);
+
+        // validation required for the scenario when a board member becomes a guardian
+        self.require_valid_quorums();
+    }
+
+    fn add_executor(&self, action_id: ActionId, executor_address: ManagedAddress) {
+        change_user_role(self, action_id, executor_address, UserRole::Executor);
+
+        // validation required for the scenario when a board member becomes an executor
+        self.require_valid_quorums();
+    }
+
+    /// Moves the role and voting weight of a board member to a new address,
+    /// so the board size and total weight never change in between.
+    /// The old address loses its signing keys and activity, like any removed board member.
+    /// Optionally, the signatures on pending actions are moved as well,
+    /// which only goes through the actions signed by the old address.
+    fn replace_board_member(
+        &self,
+        action_id: ActionId,
+        old_board_member: ManagedAddress,
+        new_board_member: ManagedAddress,
+        migrate_signatures: bool,
+    ) {
+        let (old_user_id, old_user_role) = self.get_id_and_role(&old_board_member);
+        require!(
+            old_user_role == UserRole::BoardMember,
+            "old address is not a board member"
+        );
+
+        let (_, new_user_role) = self.get_id_and_role(&new_board_member);
+        require!(
+            new_user_role == UserRole::None,
+            "new address already has a role"
+        );
+
+        let weight = self.get_user_weight(old_user_id);
+        change_user_role(self, action_id, old_board_member.clone(), UserRole::None);
+        change_user_role(
+            self,
+            action_id,
+            new_board_member.clone(),
+            UserRole::BoardMember,
+        );
+
+        let new_user_id = self.user_ids().get_id_non_zero(&new_board_member);
+        if weight != DEFAULT_WEIGHT {
+            self.user_weight(new_user_id).set(weight);
+            self.total_board_weight()
+                .update(|total_weight| *total_weight = *total_weight - DEFAULT_WEIGHT + weight);
+        }
+
+        if migrate_signatures {
+            // the signatures made before the upgrade must be indexed first
+            self.require_storage_migrated();
+
+            let mut signed_action_ids = ManagedVec::<Self::Api, ActionId>::new();
+            for signed_action_id in self.user_signed_actions(old_user_id).iter() {
+                signed_action_ids.push(signed_action_id);
+            }
+
+            for signed_action_id in &signed_action_ids {
+                self.remove_action_signer(signed_action_id, old_user_id);
+                self.add_action_signer(signed_action_id, new_user_id);
+            }
+        }
+
+        self.perform_replace_board_member_event(
+            action_id,
+            &old_board_member,
+            &new_board_member,
+            migrate_signatures,
+        );
+    }
+
+    /// Applies all the user changes and the new quorum, and only then validates the result,
+    /// so any valid final state can be reached, e.g. going from 3-of-3 to 2-of-2.
+    /// Removals are applied first, so the same address can be removed and added back with another role.
+    /// Adding an existing board member keeps their weight.
+    fn reconfigure_board(
+        &self,
+        action_id: ActionId,
+        add: ManagedVec<ManagedAddress>,
+        remove: ManagedVec<ManagedAddress>,
+        proposers_add: ManagedVec<ManagedAddress>,
+        proposers_remove: ManagedVec<ManagedAddress>,
+        new_quorum: usize,
+    ) {
+        for user_address in &remove {
+            let (_, user_role) = self.get_id_and_role(&user_address);
+            require!(
+                user_role == UserRole::BoardMember,
+                "user to remove is not a board member"
+            );
+
+            change_user_role(self, action_id, user_address, UserRole::None);
+        }
+        for user_address in &proposers_remove {
+            let (_, user_role) = self.get_id_and_role(&user_address);
+            require!(
+                user_role == UserRole::Proposer,
+                "user to remove is not a proposer"
+            );
+
+            change_user_role(self, action_id, user_address, UserRole::None);
+        }
+        for user_address in &add {
+            change_user_role(self, action_id, user_address, UserRole::BoardMember);
+        }
+        for user_address in &proposers_add {
+            change_user_role(self, action_id, user_address, UserRole::Proposer);
+        }
+
+        let num_board_members = self.num_board_members().get();
+        let num_proposers = self.num_proposers().get();
+        require!(
+            num_board_members <= self.max_board_members().get(),
+            BOARD_SIZE_TOO_BIG_ERR_MSG
+        );
+        require!(
+            num_board_members + num_proposers > 0,
+            "cannot remove all board members and proposers"
+        );
+
+        self.quorum().set(new_quorum);
+        self.require_valid_quorums();
+
+        self.perform_reconfigure_board_event(
+            action_id,
+            new_quorum,
+            num_board_members,
+            num_proposers,
+        );
     }
 
     fn remove_user(&self, action_id: ActionId, user_address: ManagedAddress) {
@@ -97,24 +346,75 @@ pub trait ExecuteActionModule:
             "cannot remove all board members and proposers"
         );
 
-        let quorum = self.quorum().get();
-        self.require_valid_quorum(quorum, num_board_members);
+        self.require_valid_quorums();
+    }
+
+    fn remove_proposer(&self, action_id: ActionId, proposer_address: ManagedAddress) {
+        let (_, user_role) = self.get_id_and_role(&proposer_address);
+        require!(user_role == UserRole::Proposer, "user is not a proposer");
+
+        self.remove_user(action_id, proposer_address);
+    }
+
+    fn demote_to_proposer(&self, action_id: ActionId, board_member_address: ManagedAddress) {
+        let (_, user_role) = self.get_id_and_role(&board_member_address);
+        require!(
+            user_role == UserRole::BoardMember,
+            "user is not a board member"
+        );
+
+        self.add_proposer(action_id, board_member_address);
+    }
+
+    fn promote_to_board_member(&self, action_id: ActionId, proposer_address: ManagedAddress) {
+        let (_, user_role) = self.get_id_and_role(&proposer_address);
+        require!(user_role == UserRole::Proposer, "user is not a proposer");
+
+        self.add_board_member(action_id, proposer_address);
     }
 
     fn change_quorum(&self, action_id: ActionId, new_quorum: usize) {
-        let board_members = self.num_board_members().get();
-        self.require_valid_quorum(new_quorum, board_members);
+        self.require_valid_quorum(new_quorum);
 
         self.quorum().set(new_quorum);
-        self.perform_change_quorum_event(action_id, new_quorum);
+        self.perform_change_quorum_event(action_id, new_quorum, None);
+    }
+
+    fn change_action_kind_quorum(
+        &self,
+        action_id: ActionId,
+        action_kind: ActionKind,
+        opt_quorum: Option<usize>,
+    ) {
+        require!(
+            action_kind != ActionKind::Unpause,
+            "unpause always requires the whole board"
+        );
+
+        let new_quorum = match opt_quorum {
+            Some(quorum) => {
+                self.require_valid_quorum(quorum);
+                let _ = self.action_kind_quorum().insert(action_kind, quorum);
+
+                quorum
+            }
+            None => {
+                let _ = self.action_kind_quorum().remove(&action_kind);
+
+                self.quorum().get()
+            }
+        };
+
+        self.perform_change_quorum_event(action_id, new_quorum, Some(action_kind));
     }
 
     fn add_module(&self, action_id: ActionId, sc_address: ManagedAddress) {
+        let max_modules = self.max_modules().get();
         self.nr_deployed_modules().update(|nr_deployed_modules| {
             *nr_deployed_modules += 1;
 
             require!(
-                *nr_deployed_modules <= MAX_MODULES,
+                *nr_deployed_modules <= max_modules,
                 "May not add more modules"
             );
         });
@@ -129,6 +429,8 @@ pub trait ExecuteActionModule:
         let module_id = self.module_id().remove_by_address(&sc_address);
         if module_id != NULL_ID {
             let _ = self.active_modules_ids().swap_remove(&module_id);
+            self.module_notifications_enabled(module_id).clear();
+            self.module_action_approval_enabled(module_id).clear();
 
             self.nr_deployed_modules()
                 .update(|nr_deployed_modules| *nr_deployed_modules -= 1);
@@ -137,6 +439,211 @@ pub trait ExecuteActionModule:
         self.perform_remove_module_event(action_id, &sc_address);
     }
 
+    fn set_module_notifications(
+        &self,
+        action_id: ActionId,
+        sc_address: ManagedAddress,
+        enabled: bool,
+    ) {
+        let module_id = self.module_id().get_id(&sc_address);
+        require!(module_id != NULL_ID, "Unknown module");
+
+        self.module_notifications_enabled(module_id).set(enabled);
+        self.perform_set_module_notifications_event(action_id, &sc_address, enabled);
+    }
+
+    fn set_module_action_approval(
+        &self,
+        action_id: ActionId,
+        sc_address: ManagedAddress,
+        enabled: bool,
+    ) {
+        let module_id = self.module_id().get_id(&sc_address);
+        require!(module_id != NULL_ID, "Unknown module");
+
+        self.module_action_approval_enabled(module_id).set(enabled);
+        self.perform_set_module_action_approval_event(action_id, &sc_address, enabled);
+    }
+
+    fn unpause(&self, action_id: ActionId) {
+        self.paused().clear();
+        self.perform_unpause_event(action_id);
+    }
+
+    /// Replaces all recovery addresses. An empty list disables recovery, in which case the challenge period is not checked.
+    /// Any pending recovery is dropped, as its initiator might no longer be a recovery address.
+    fn set_recovery_config(
+        &self,
+        action_id: ActionId,
+        recovery_addresses: ManagedVec<ManagedAddress>,
+        challenge_period: Timestamp,
+    ) {
+        require!(
+            recovery_addresses.len() <= MAX_RECOVERY_ADDRESSES,
+            "too many recovery addresses"
+        );
+        require!(
+            recovery_addresses.is_empty()
+                || (MIN_RECOVERY_CHALLENGE_PERIOD..=MAX_RECOVERY_CHALLENGE_PERIOD)
+                    .contains(&challenge_period),
+            "invalid recovery challenge period"
+        );
+
+        let mut mapper = self.recovery_addresses();
+        mapper.clear();
+        for address in &recovery_addresses {
+            let _ = mapper.insert(address);
+        }
+
+        self.recovery_challenge_period().set(challenge_period);
+        self.pending_recovery().clear();
+
+        self.perform_set_recovery_config_event(action_id, challenge_period, &recovery_addresses);
+    }
+
+    fn set_max_board_members(&self, action_id: ActionId, max_board_members: usize) {
+        self.require_valid_max_board_members(max_board_members);
+
+        self.max_board_members().set(max_board_members);
+        self.perform_set_max_board_members_event(action_id, max_board_members);
+    }
+
+    fn set_max_modules(&self, action_id: ActionId, max_modules: usize) {
+        self.require_valid_max_modules(max_modules);
+
+        self.max_modules().set(max_modules);
+        self.perform_set_max_modules_event(action_id, max_modules);
+    }
+
+    /// Applies to async calls performed from now on, including pending ones that failed before.
+    fn set_max_async_call_retries(&self, action_id: ActionId, max_retries: usize) {
+        require!(
+            max_retries <= MAX_ASYNC_CALL_RETRIES_LIMIT,
+            "max async call retries too big"
+        );
+
+        self.max_async_call_retries().set(max_retries);
+        self.perform_set_max_async_call_retries_event(action_id, max_retries);
+    }
+
+    /// Missing limits keep their current value, or get the default one if never set,
+    /// e.g. when upgrading from a version without configurable limits.
+    fn init_limits(&self, opt_max_board_members: Option<usize>, opt_max_modules: Option<usize>) {
+        let max_board_members_mapper = self.max_board_members();
+        let max_board_members = opt_max_board_members.unwrap_or_else(|| {
+            if max_board_members_mapper.is_empty() {
+                DEFAULT_MAX_BOARD_MEMBERS
+            } else {
+                max_board_members_mapper.get()
+            }
+        });
+        self.require_valid_max_board_members(max_board_members);
+        max_board_members_mapper.set(max_board_members);
+
+        let max_modules_mapper = self.max_modules();
+        let max_modules = opt_max_modules.unwrap_or_else(|| {
+            if max_modules_mapper.is_empty() {
+                DEFAULT_MAX_MODULES
+            } else {
+                max_modules_mapper.get()
+            }
+        });
+        self.require_valid_max_modules(max_modules);
+        max_modules_mapper.set(max_modules);
+    }
+
+    /// Only the first call has an effect, so upgrades keep the moment the timestamps were introduced.
+    fn init_quorum_tracking(&self) {
+        let mapper = self.quorum_tracking_start_timestamp();
+        if mapper.is_empty() {
+            mapper.set(self.blockchain().get_block_timestamp());
+        }
+    }
+
+    fn require_valid_max_board_members(&self, max_board_members: usize) {
+        require!(
+            max_board_members > 0 && max_board_members <= MAX_BOARD_MEMBERS_LIMIT,
+            "invalid max board members"
+        );
+        require!(
+            self.num_board_members().get() <= max_board_members,
+            "max board members cannot be below board size"
+        );
+    }
+
+    fn require_valid_max_modules(&self, max_modules: usize) {
+        require!(
+            max_modules > 0 && max_modules <= MAX_MODULES_LIMIT,
+            "invalid max modules"
+        );
+        require!(
+            self.nr_deployed_modules().get() <= max_modules,
+            "max modules cannot be below number of modules"
+        );
+    }
+
+    fn set_execution_delay(&self, action_id: ActionId, delay: Timestamp) {
+        require!(
+            delay <= MAX_EXECUTION_DELAY,
+            EXECUTION_DELAY_TOO_BIG_ERR_MSG
+        );
+
+        self.execution_delay().set(delay);
+        self.perform_set_execution_delay_event(action_id, None, Some(delay));
+    }
+
+    fn set_action_kind_execution_delay(
+        &self,
+        action_id: ActionId,
+        action_kind: ActionKind,
+        opt_delay: Option<Timestamp>,
+    ) {
+        if let Some(delay) = opt_delay {
+            require!(
+                delay <= MAX_EXECUTION_DELAY,
+                EXECUTION_DELAY_TOO_BIG_ERR_MSG
+            );
+        }
+
+        self.action_kind_execution_delay(action_kind).set(opt_delay);
+        self.perform_set_execution_delay_event(action_id, Some(action_kind), opt_delay);
+    }
+
+    fn set_board_member_weight(
+        &self,
+        action_id: ActionId,
+        board_member: ManagedAddress,
+        weight: Weight,
+    ) {
+        require!(weight > 0, "weight cannot be zero");
+        require!(
+            weight <= MAX_BOARD_MEMBER_WEIGHT,
+            "board member weight too big"
+        );
+
+        let (user_id, user_role) = self.get_id_and_role(&board_member);
+        require!(
+            user_role == UserRole::BoardMember,
+            "only board members have voting weight"
+        );
+
+        let old_weight = self.get_user_weight(user_id);
+        self.total_board_weight()
+            .update(|total_weight| *total_weight = *total_weight - old_weight + weight);
+        if weight == DEFAULT_WEIGHT {
+            self.user_weight(user_id).clear();
+        } else {
+            self.user_weight(user_id).set(weight);
+        }
+        self.signing_power_changed_timestamp(user_id)
+            .set(self.blockchain().get_block_timestamp());
+
+        // validation required for the scenario when the weight is lowered
+        self.require_valid_quorums();
+
+        self.perform_set_board_member_weight_event(action_id, &board_member, old_weight, weight);
+    }
+
     fn send_transfer_execute_egld(
         &self,
         action_id: ActionId,
@@ -197,7 +704,14 @@ pub trait ExecuteActionModule:
         }
     }
 
-    fn send_async_call(&self, action_id: ActionId, call_data: CallActionData<Self::Api>) {
+    /// The group ID is passed to the callback along with the action ID, so the result of the batch can be recorded.
+    /// It is 0 for actions performed on their own.
+    fn send_async_call(
+        &self,
+        action_id: ActionId,
+        group_id: GroupId,
+        call_data: CallActionData<Self::Api>,
+    ) {
         let gas = call_data
             .opt_gas_limit
             .unwrap_or_else(|| self.ensure_and_get_gas_for_transfer_exec());
@@ -215,10 +729,93 @@ pub trait ExecuteActionModule:
             .with_raw_arguments(call_data.arguments.into())
             .with_gas_limit(gas)
             .async_call()
-            .with_callback(self.callbacks().perform_async_call_callback())
+            .with_callback(
+                self.callbacks()
+                    .perform_async_call_callback(action_id, group_id),
+            )
             .call_and_exit();
     }
 
+    /// Unlike transfer-execute, promises can target contracts in other shards.
+    /// The gas limit is always explicit, since several calls can be sent in the same transaction.
+    fn send_promise_call_egld(
+        &self,
+        action_id: ActionId,
+        group_id: GroupId,
+        call_data: CallActionData<Self::Api>,
+    ) {
+        let gas = self.require_gas_for_promise_call(call_data.opt_gas_limit);
+        self.perform_promise_call_egld_event(
+            action_id,
+            &call_data.to,
+            &call_data.egld_amount,
+            gas,
+            &call_data.endpoint_name,
+            call_data.arguments.as_multi(),
+        );
+
+        self.promise_call_status(action_id)
+            .set(PromiseCallStatus::Pending);
+        self.send()
+            .contract_call::<()>(call_data.to, call_data.endpoint_name)
+            .with_egld_transfer(call_data.egld_amount)
+            .with_raw_arguments(call_data.arguments.into())
+            .with_gas_limit(gas)
+            .async_call_promise()
+            .with_extra_gas_for_callback(PROMISE_CALLBACK_GAS)
+            .with_callback(
+                self.callbacks()
+                    .perform_promise_call_callback(action_id, group_id),
+            )
+            .register_promise();
+    }
+
+    fn send_promise_call_esdt(
+        &self,
+        action_id: ActionId,
+        group_id: GroupId,
+        call_data: EsdtTransferExecuteData<Self::Api>,
+    ) {
+        let gas = self.require_gas_for_promise_call(call_data.opt_gas_limit);
+        self.perform_promise_call_esdt_event(
+            action_id,
+            &call_data.to,
+            &call_data.tokens,
+            gas,
+            &call_data.endpoint_name,
+            call_data.arguments.as_multi(),
+        );
+
+        self.promise_call_status(action_id)
+            .set(PromiseCallStatus::Pending);
+        self.send()
+            .contract_call::<()>(call_data.to, call_data.endpoint_name)
+            .with_multi_token_transfer(call_data.tokens)
+            .with_raw_arguments(call_data.arguments.into())
+            .with_gas_limit(gas)
+            .async_call_promise()
+            .with_extra_gas_for_callback(PROMISE_CALLBACK_GAS)
+            .with_callback(
+                self.callbacks()
+                    .perform_promise_call_callback(action_id, group_id),
+            )
+            .register_promise();
+    }
+
+    fn require_gas_for_promise_call(&self, opt_gas_limit: Option<GasLimit>) -> GasLimit {
+        let gas = match opt_gas_limit {
+            Some(gas) => gas,
+            None => sc_panic!(PROMISE_CALL_GAS_LIMIT_REQUIRED_ERR_MSG),
+        };
+        require!(
+            self.blockchain().get_gas_left()
+                > gas + PROMISE_CALLBACK_GAS + PERFORM_ACTION_FINISH_GAS,
+            "insufficient gas for call"
+        );
+
+        gas
+    }
+
     fn deploy_from_source(
         &self,
         action_id: ActionId,
@@ -240,13 +837,18 @@ pub trait ExecuteActionModule:
             args.code_metadata,
             &args.arguments.into(),
         );
+        self.contract_deployed_event(action_id, &new_address);
 
         new_address
     }
 
+    /// The upgrade is an async call without a callback of its own,
+    /// so the callback closure is saved the same way `call_and_exit` does for async calls.
+    /// The VM calls it back whether the upgrade succeeds or fails, which completes a batch ending with the upgrade.
     fn upgrade_from_source(
         &self,
         action_id: ActionId,
+        group_id: GroupId,
         sc_address: ManagedAddress,
         args: DeployArgs<Self::Api>,
     ) {
@@ -260,6 +862,9 @@ pub trait ExecuteActionModule:
             gas_left,
             args.arguments.as_multi(),
         );
+        self.callbacks()
+            .perform_async_call_callback(action_id, group_id)
+            .save_to_storage::<Self::Api>();
         self.send_raw().upgrade_from_source_contract(
             &sc_address,
             gas_left,
@@ -270,14 +875,18 @@ pub trait ExecuteActionModule:
         );
     }
 
-    fn clear_action(&self, action_id: ActionId) {
-        self.action_mapper().clear_entry_unchecked(action_id);
-        self.action_signer_ids(action_id).clear();
-
-        let group_id = self.group_for_action(action_id).take();
-        if group_id != 0 {
-            let _ = self.action_groups(group_id).swap_remove(&action_id);
-        }
+    /// Calls the `ChangeOwnerAddress` builtin function, which only succeeds if the multisig owns the target.
+    /// This also applies to changing the owner of the multisig itself, i.e. the multisig has to be its own owner.
+    fn change_owner(
+        &self,
+        action_id: ActionId,
+        sc_address: ManagedAddress,
+        new_owner: ManagedAddress,
+    ) {
+        self.perform_change_owner_event(action_id, &sc_address, &new_owner);
+        self.send()
+            .change_owner_address(sc_address, &new_owner)
+            .execute_on_dest_context::<()>();
     }
 
     fn ensure_and_get_gas_for_transfer_exec(&self) -> GasLimit {
@@ -290,10 +899,25 @@ pub trait ExecuteActionModule:
         gas_left - PERFORM_ACTION_FINISH_GAS
     }
 
-    fn require_valid_quorum(&self, quorum: usize, num_board_members: usize) {
+    /// Checks the default quorum and all the per action type overrides.
+    /// Required whenever the board weight decreases.
+    fn require_valid_quorums(&self) {
+        let mut max_quorum = self.quorum().get();
+        for quorum in self.action_kind_quorum().values() {
+            if quorum > max_quorum {
+                max_quorum = quorum;
+            }
+        }
+
+        self.require_valid_quorum(max_quorum);
+    }
+
+    /// The quorum is compared against the total weight of the board,
+    /// which is the board size when all members have the default weight.
+    fn require_valid_quorum(&self, quorum: usize) {
         require!(
-            quorum <= num_board_members,
-            "quorum cannot exceed board size"
+            quorum <= self.total_board_weight().get(),
+            QUORUM_TOO_BIG_ERR_MSG
         );
     }
 }
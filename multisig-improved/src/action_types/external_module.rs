@@ -1,24 +1,59 @@
 use multiversx_sc_modules::transfer_role_proxy::PaymentsVec;
 
+use crate::common_types::action::{Action, ActionId, ModuleExecutionInfo};
+
 multiversx_sc::imports!();
 
 pub type ModuleId = AddressId;
 
+pub const MAX_RECENT_MODULE_EXECUTIONS: usize = 50;
+
 pub struct CanExecuteArgs<'a, M: ManagedTypeApi> {
     pub proposer: &'a ManagedAddress<M>,
-    pub sc_address: &'a ManagedAddress<M>,
-    pub endpoint_name: &'a ManagedBuffer<M>,
-    pub egld_value: &'a BigUint<M>,
-    pub esdt_payments: &'a PaymentsVec<M>,
+    pub action: &'a Action<M>,
+}
+
+/// Arguments of the original `canExecute` interface, only available for transfer-execute actions.
+struct TransferArgs<M: ManagedTypeApi> {
+    sc_address: ManagedAddress<M>,
+    endpoint_name: ManagedBuffer<M>,
+    egld_value: BigUint<M>,
+    esdt_payments: PaymentsVec<M>,
+}
+
+impl<M: ManagedTypeApi> TransferArgs<M> {
+    fn from_action(action: &Action<M>) -> Option<Self> {
+        match action {
+            Action::SendTransferExecuteEgld(call_data) => Some(TransferArgs {
+                sc_address: call_data.to.clone(),
+                endpoint_name: call_data.endpoint_name.clone(),
+                egld_value: call_data.egld_amount.clone(),
+                esdt_payments: PaymentsVec::new(),
+            }),
+            Action::SendTransferExecuteEsdt(call_data) => Some(TransferArgs {
+                sc_address: call_data.to.clone(),
+                endpoint_name: call_data.endpoint_name.clone(),
+                egld_value: BigUint::zero(),
+                esdt_payments: call_data.tokens.clone(),
+            }),
+            _ => None,
+        }
+    }
 }
 
 mod external_module_proxy {
     use multiversx_sc_modules::transfer_role_proxy::PaymentsVec;
 
+    use crate::common_types::action::{Action, ActionId};
+
     multiversx_sc::imports!();
 
     #[multiversx_sc::proxy]
     pub trait ExternalModuleProxy {
+        /// Only called for modules with action approval enabled.
+        #[view(canExecuteAction)]
+        fn can_execute_action(&self, proposer: ManagedAddress, action: Action<Self::Api>) -> bool;
+
         #[view(canExecute)]
         fn can_execute(
             &self,
@@ -28,37 +63,134 @@ mod external_module_proxy {
             egld_value: BigUint,
             esdt_payments: PaymentsVec<Self::Api>,
         ) -> bool;
+
+        #[endpoint(onActionExecuted)]
+        fn on_action_executed(
+            &self,
+            action_id: ActionId,
+            proposer: ManagedAddress,
+            sc_address: ManagedAddress,
+            endpoint_name: ManagedBuffer,
+            egld_value: BigUint,
+            esdt_payments: PaymentsVec<Self::Api>,
+        );
     }
 }
 
 #[multiversx_sc::module]
 pub trait ExternalModuleModule:
-    crate::common_functions::CommonFunctionsModule + crate::state::StateModule
+    crate::common_functions::CommonFunctionsModule
+    + crate::state::StateModule
+    + crate::external::events::EventsModule
 {
-    fn can_execute_action(&self, args: CanExecuteArgs<Self::Api>) -> bool {
-        let module_id_mapper = self.module_id();
+    /// Returns the first active module that allows executing the action without signatures.
+    /// Modules with action approval enabled receive the full action through `canExecuteAction`,
+    /// all other modules can only approve transfer-execute actions, through `canExecute`.
+    fn get_approving_module(&self, args: &CanExecuteArgs<Self::Api>) -> Option<ModuleId> {
+        if !args.action.is_external_call() {
+            return None;
+        }
+
+        let opt_transfer_args = TransferArgs::from_action(args.action);
         for module_id in self.active_modules_ids().iter() {
-            let opt_module_address = module_id_mapper.get_address(module_id);
-            require!(opt_module_address.is_some(), "Invalid setup");
-
-            let module_address = unsafe { opt_module_address.unwrap_unchecked() };
-            let can_execute: bool = self
-                .external_sc_proxy(module_address)
-                .can_execute(
-                    args.proposer.clone(),
-                    args.sc_address.clone(),
-                    args.endpoint_name.clone(),
-                    args.egld_value.clone(),
-                    args.esdt_payments.clone(),
-                )
-                .execute_on_dest_context();
+            let module_address = self.get_module_address(module_id);
+            let can_execute = if self.module_action_approval_enabled(module_id).get() {
+                self.external_sc_proxy(module_address)
+                    .can_execute_action(args.proposer.clone(), args.action.clone())
+                    .execute_on_dest_context()
+            } else if let Some(transfer_args) = &opt_transfer_args {
+                self.external_sc_proxy(module_address)
+                    .can_execute(
+                        args.proposer.clone(),
+                        transfer_args.sc_address.clone(),
+                        transfer_args.endpoint_name.clone(),
+                        transfer_args.egld_value.clone(),
+                        transfer_args.esdt_payments.clone(),
+                    )
+                    .execute_on_dest_context()
+            } else {
+                false
+            };
 
             if can_execute {
-                return true;
+                return Some(module_id);
             }
         }
 
-        false
+        None
+    }
+
+    /// Lets the approving module know the action was executed, e.g. to keep track of spent amounts.
+    /// Only modules that enabled notifications are called, and only for transfer-execute actions.
+    /// Async calls, deploys, upgrades and batches approved through `canExecuteAction` are not notified,
+    /// as the `onActionExecuted` arguments only describe transfers.
+    fn notify_action_executed(
+        &self,
+        module_id: ModuleId,
+        action_id: ActionId,
+        args: CanExecuteArgs<Self::Api>,
+    ) {
+        if !self.module_notifications_enabled(module_id).get() {
+            return;
+        }
+
+        let transfer_args = match TransferArgs::from_action(args.action) {
+            Some(transfer_args) => transfer_args,
+            None => return,
+        };
+
+        let module_address = self.get_module_address(module_id);
+        self.external_sc_proxy(module_address)
+            .on_action_executed(
+                action_id,
+                args.proposer.clone(),
+                transfer_args.sc_address,
+                transfer_args.endpoint_name,
+                transfer_args.egld_value,
+                transfer_args.esdt_payments,
+            )
+            .execute_on_dest_context::<()>();
+    }
+
+    /// Keeps track of the last `MAX_RECENT_MODULE_EXECUTIONS` actions approved by modules.
+    fn record_module_execution(
+        &self,
+        action_id: ActionId,
+        module_address: &ManagedAddress,
+        proposer: &ManagedAddress,
+    ) {
+        self.module_approved_action_event(action_id, module_address, proposer);
+
+        let mut mapper = self.recent_module_executions();
+        if mapper.len() == MAX_RECENT_MODULE_EXECUTIONS {
+            let _ = mapper.pop_front();
+        }
+
+        mapper.push_back(ModuleExecutionInfo {
+            action_id,
+            module_address: module_address.clone(),
+            proposer: proposer.clone(),
+            timestamp: self.blockchain().get_block_timestamp(),
+        });
+    }
+
+    fn get_module_address(&self, module_id: ModuleId) -> ManagedAddress {
+        let opt_module_address = self.module_id().get_address(module_id);
+        require!(opt_module_address.is_some(), "Invalid setup");
+
+        unsafe { opt_module_address.unwrap_unchecked() }
+    }
+
+    #[view(getModuleNotificationsEnabled)]
+    fn get_module_notifications_enabled(&self, sc_address: ManagedAddress) -> bool {
+        let module_id = self.module_id().get_id(&sc_address);
+        module_id != NULL_ID && self.module_notifications_enabled(module_id).get()
+    }
+
+    #[view(getModuleActionApprovalEnabled)]
+    fn get_module_action_approval_enabled(&self, sc_address: ManagedAddress) -> bool {
+        let module_id = self.module_id().get_id(&sc_address);
+        module_id != NULL_ID && self.module_action_approval_enabled(module_id).get()
     }
 
     #[proxy]
@@ -74,6 +206,20 @@ pub trait ExternalModuleModule:
     #[storage_mapper("nrDeployModules")]
     fn nr_deployed_modules(&self) -> SingleValueMapper<usize>;
 
+    /// Can be changed by the board, up to a gas-bounded maximum.
+    #[view(getMaxModules)]
+    #[storage_mapper("maxModules")]
+    fn max_modules(&self) -> SingleValueMapper<usize>;
+
     #[storage_mapper("activeModulesIds")]
     fn active_modules_ids(&self) -> UnorderedSetMapper<ModuleId>;
+
+    #[storage_mapper("moduleNotifications")]
+    fn module_notifications_enabled(&self, module_id: ModuleId) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("moduleActionApproval")]
+    fn module_action_approval_enabled(&self, module_id: ModuleId) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("recentModuleExecutions")]
+    fn recent_module_executions(&self) -> QueueMapper<ModuleExecutionInfo<Self::Api>>;
 }
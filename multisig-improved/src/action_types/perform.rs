@@ -1,10 +1,12 @@
-use multiversx_sc_modules::transfer_role_proxy::PaymentsVec;
-
-use crate::common_types::action::{
-    ActionFullInfo, ActionId, ActionStatus, CallActionData, EsdtTransferExecuteData,
+use crate::common_types::{
+    action::{
+        Action, ActionFullInfo, ActionId, ActionStatus, AsyncCallRetryInfo, GroupExecutionStatus,
+        GroupId,
+    },
+    user_role::UserRole,
 };
 
-use super::external_module::CanExecuteArgs;
+use super::external_module::{CanExecuteArgs, ModuleId};
 
 multiversx_sc::imports!();
 
@@ -18,7 +20,20 @@ pub trait PerformModule:
     + crate::ms_endpoints::callbacks::CallbacksModule
 {
     fn perform_action_by_id(&self, action_id: ActionId) -> OptionalValue<ManagedAddress> {
+        self.perform_action_approved_by(action_id, None)
+    }
+
+    fn perform_action_approved_by(
+        &self,
+        action_id: ActionId,
+        approving_module: Option<ManagedAddress>,
+    ) -> OptionalValue<ManagedAddress> {
+        self.require_action_not_expired(action_id);
+
         let action = self.action_mapper().get(action_id);
+        self.require_action_allowed_if_paused(&action);
+        self.require_whole_board_for_unpause(action_id, &action);
+        self.require_no_async_call_in_progress(action_id);
 
         let group_id = self.group_for_action(action_id).get();
         if group_id != 0 {
@@ -29,77 +44,130 @@ pub trait PerformModule:
             );
         }
 
-        self.start_perform_action_event(&ActionFullInfo {
-            action_id,
-            action_data: action.clone(),
-            signers: self.get_action_signers(action_id),
-            group_id,
-        });
+        self.start_perform_action_event(
+            &approving_module,
+            &ActionFullInfo {
+                action_id,
+                action_data: action.clone(),
+                signers: self.get_action_signers(action_id),
+                group_id,
+                deadline: self.action_deadline(action_id).get(),
+            },
+        );
 
         // clean up storage
         // happens before actual execution, because the match provides the return on each branch
         // syntax aside, the async_call_raw kills contract execution so cleanup cannot happen afterwards
-        self.clear_action(action_id);
+        // async calls that can be retried are kept until the callback decides their fate
+        if approving_module.is_none() && self.is_async_call_retryable(&action) {
+            self.start_async_call_attempt(action_id);
+        } else {
+            self.clear_action(action_id);
+        }
 
         let opt_address = self.try_execute_deploy(action_id, &action);
         if opt_address.is_some() {
             return opt_address;
         }
 
-        self.execute_action_by_type(action_id, action);
+        self.execute_action_by_type(action_id, group_id, action);
 
         OptionalValue::None
     }
 
-    fn try_perform_egld_action_directly(
+    /// Executes the action without signatures if a module approves it.
+    fn try_perform_action_directly(
         &self,
         proposer: &ManagedAddress,
         action_id: ActionId,
-        call_data: &CallActionData<Self::Api>,
+        action: &Action<Self::Api>,
     ) -> bool {
-        let can_execute = self.can_execute_action(CanExecuteArgs {
-            proposer,
-            sc_address: &call_data.to,
-            endpoint_name: &call_data.endpoint_name,
-            egld_value: &call_data.egld_amount,
-            esdt_payments: &PaymentsVec::new(),
-        });
-        if !can_execute {
-            return false;
-        }
+        let args = CanExecuteArgs { proposer, action };
+        let module_id = match self.get_approving_module(&args) {
+            Some(module_id) => module_id,
+            None => return false,
+        };
 
-        let _ = self.perform_action_by_id(action_id);
+        self.perform_module_approved_action(module_id, action_id, args);
 
         true
     }
 
-    fn try_perform_esdt_action_directly(
+    fn perform_module_approved_action(
         &self,
-        proposer: &ManagedAddress,
+        module_id: ModuleId,
         action_id: ActionId,
-        call_data: &EsdtTransferExecuteData<Self::Api>,
-    ) -> bool {
-        let can_execute = self.can_execute_action(CanExecuteArgs {
-            proposer,
-            sc_address: &call_data.to,
-            endpoint_name: &call_data.endpoint_name,
-            egld_value: &BigUint::zero(),
-            esdt_payments: &call_data.tokens,
-        });
-        if !can_execute {
-            return false;
+        args: CanExecuteArgs<Self::Api>,
+    ) {
+        let module_address = self.get_module_address(module_id);
+        self.record_module_execution(action_id, &module_address, args.proposer);
+
+        // proposing through a module counts as activity, even though nobody signs
+        let (proposer_id, proposer_role) = self.get_id_and_role(args.proposer);
+        if proposer_role != UserRole::None {
+            self.record_member_activity(proposer_id);
         }
 
-        let _ = self.perform_action_by_id(action_id);
+        let _ = self.perform_action_approved_by(action_id, Some(module_address));
+        self.notify_action_executed(module_id, action_id, args);
+    }
 
-        true
+    /// Every action of the batch is checked before executing any of them,
+    /// then they are executed in the order they were proposed.
+    /// If the batch ends with an async call or an upgrade, it only completes once the callback was received.
+    /// The same goes for batches with promise calls, once all their callbacks were received.
+    fn perform_batch_in_order(&self, group_id: GroupId) {
+        let group_status = self.action_group_status(group_id).get();
+        require!(
+            group_status == ActionStatus::Available,
+            "cannot perform actions of an aborted batch"
+        );
+
+        let action_ids = self.get_group_actions_in_order(group_id);
+        require!(!action_ids.is_empty(), "Invalid group ID");
+
+        let mut ends_with_async_call = false;
+        let mut num_promise_calls = 0;
+        for action_id in &action_ids {
+            self.require_action_not_expired(action_id);
+            require!(
+                self.quorum_reached(action_id),
+                "quorum has not been reached"
+            );
+            self.require_execution_delay_passed(action_id);
+
+            let action = self.action_mapper().get(action_id);
+            self.require_action_allowed_if_paused(&action);
+            ends_with_async_call = action.ends_execution();
+            if action.is_promise_call() {
+                num_promise_calls += 1;
+            }
+        }
+
+        // the async call ends the execution, so the status has to be set beforehand
+        // the promise calls are only sent at the end of the transaction, so their callbacks always come after this
+        let group_status = if ends_with_async_call {
+            GroupExecutionStatus::AsyncCallPending
+        } else if num_promise_calls > 0 {
+            self.group_pending_promise_calls(group_id)
+                .set(num_promise_calls);
+            GroupExecutionStatus::PromiseCallsPending
+        } else {
+            GroupExecutionStatus::Succeeded
+        };
+        self.group_execution_status(group_id).set(group_status);
+
+        for action_id in &action_ids {
+            let _ = self.perform_action_by_id(action_id);
+        }
     }
 
     fn try_perform_action(&self, action_id: ActionId) -> OptionalValue<ManagedAddress> {
-        let (_, caller_role) = self.get_caller_id_and_role();
+        let (caller_id, caller_role) = self.get_caller_id_and_role();
         caller_role.require_can_perform_action::<Self::Api>();
+        self.record_member_activity(caller_id);
 
-        if !self.quorum_reached(action_id) {
+        if !self.quorum_reached(action_id) || !self.is_execution_delay_passed(action_id) {
             return OptionalValue::None;
         }
 
@@ -109,11 +177,33 @@ pub trait PerformModule:
         self.perform_action_by_id(action_id)
     }
 
-    fn require_same_shard(&self, sc_address: &ManagedAddress) {
-        if cfg!(debug_assertions) {
-            return;
-        }
+    fn is_async_call_retryable(&self, action: &Action<Self::Api>) -> bool {
+        action.is_async_call() && self.max_async_call_retries().get() > 0
+    }
+
+    fn start_async_call_attempt(&self, action_id: ActionId) {
+        let retry_mapper = self.async_call_retry(action_id);
+        let previous_attempts = if retry_mapper.is_empty() {
+            0
+        } else {
+            retry_mapper.get().attempts
+        };
+
+        retry_mapper.set(AsyncCallRetryInfo {
+            attempts: previous_attempts + 1,
+            in_progress: true,
+        });
+    }
 
+    fn require_no_async_call_in_progress(&self, action_id: ActionId) {
+        let retry_mapper = self.async_call_retry(action_id);
+        require!(
+            retry_mapper.is_empty() || !retry_mapper.get().in_progress,
+            "async call already in progress"
+        );
+    }
+
+    fn require_same_shard(&self, sc_address: &ManagedAddress) {
         let own_address = self.blockchain().get_sc_address();
         let own_shard = self.blockchain().get_shard_of_address(&own_address);
         let sc_shard = self.blockchain().get_shard_of_address(sc_address);
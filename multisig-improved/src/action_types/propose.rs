@@ -1,5 +1,6 @@
+use crate::action_types::execute_action::PROMISE_CALL_GAS_LIMIT_REQUIRED_ERR_MSG;
 use crate::common_types::{
-    action::{Action, ActionId},
+    action::{Action, ActionId, Deadline},
     signature::SignatureArg,
 };
 
@@ -16,48 +17,72 @@ pub trait ProposeModule:
     fn propose_action(
         &self,
         action: &Action<Self::Api>,
+        opt_deadline: Option<Deadline>,
         opt_signature: OptionalValue<SignatureArg<Self::Api>>,
     ) -> ActionId {
-        let action_id = self.add_action(action);
-        self.check_proposer_role_and_sign(action_id, action, opt_signature);
+        let action_id = self.add_action(action, opt_deadline);
+        self.check_proposer_role_and_sign(action_id, action, &opt_deadline, opt_signature);
 
         action_id
     }
 
-    fn add_action(&self, action: &Action<Self::Api>) -> ActionId {
+    fn add_action(&self, action: &Action<Self::Api>, opt_deadline: Option<Deadline>) -> ActionId {
+        self.require_action_allowed_if_paused(action);
+
         let action_id = self.action_mapper().push(action);
-        let quorum = self.quorum().get();
+        let quorum = self.get_quorum_for_action_kind(action.kind());
         self.quorum_for_action(action_id).set(quorum);
+        self.set_action_deadline(action_id, opt_deadline);
 
         action_id
     }
 
+    fn set_action_deadline(&self, action_id: ActionId, opt_deadline: Option<Deadline>) {
+        if let Some(deadline) = opt_deadline {
+            require!(
+                !self.is_deadline_passed(&deadline),
+                "deadline already passed"
+            );
+
+            self.action_deadline(action_id).set(Some(deadline));
+        }
+    }
+
     fn check_proposer_role_and_sign(
         &self,
         action_id: ActionId,
         action: &Action<Self::Api>,
+        opt_deadline: &Option<Deadline>,
         opt_signature: OptionalValue<SignatureArg<Self::Api>>,
     ) {
-        let proposer = self.get_proposer(action, opt_signature);
-        let (proposer_id, proposer_role) = self.get_id_and_role(&proposer);
+        let proposer = self.get_proposer(action, opt_deadline, opt_signature);
+        self.require_proposer_role_and_sign(action_id, &proposer);
+    }
+
+    fn require_proposer_role_and_sign(&self, action_id: ActionId, proposer: &ManagedAddress) {
+        let (proposer_id, proposer_role) = self.get_id_and_role(proposer);
         proposer_role.require_can_propose::<Self::Api>();
+        self.record_member_activity(proposer_id);
 
         if proposer_role.can_sign() {
             // also sign
             // since the action is newly created, the proposer can be the only signer
-            let _ = self.action_signer_ids(action_id).insert(proposer_id);
+            self.add_action_signer(action_id, proposer_id);
+            self.record_board_activity();
+            self.record_quorum_reached(action_id, false);
         }
     }
 
     fn get_proposer(
         &self,
         action: &Action<Self::Api>,
+        opt_deadline: &Option<Deadline>,
         opt_signature: OptionalValue<SignatureArg<Self::Api>>,
     ) -> ManagedAddress {
         match opt_signature {
             OptionalValue::Some(sig_arg) => {
                 let proposer = sig_arg.user_address.clone();
-                self.check_proposal_signature(action, sig_arg);
+                self.check_proposal_signature(action, opt_deadline, sig_arg);
 
                 proposer
             }
@@ -65,20 +90,32 @@ pub trait ProposeModule:
         }
     }
 
-    fn get_proposer_no_sig_check(
-        &self,
-        opt_signature: &OptionalValue<SignatureArg<Self::Api>>,
-    ) -> ManagedAddress {
-        match opt_signature {
-            OptionalValue::Some(sig_arg) => sig_arg.user_address.clone(),
-            OptionalValue::None => self.blockchain().get_caller(),
+    /// Promise calls and legacy async calls (including upgrades) cannot be sent in the same transaction.
+    fn require_no_mixed_async_calls(&self, actions: &MultiValueEncoded<Action<Self::Api>>) {
+        let mut has_promise_call = false;
+        let mut has_legacy_async_call = false;
+        for action in actions.clone() {
+            has_promise_call |= action.is_promise_call();
+            has_legacy_async_call |= action.ends_execution();
         }
+
+        require!(
+            !(has_promise_call && has_legacy_async_call),
+            "promise calls cannot be combined with async calls or upgrades"
+        );
     }
 
-    fn require_valid_action_type(&self, action: &Action<Self::Api>) {
+    /// Nothing can be performed after an async call or an upgrade,
+    /// so a batch can only contain one of them, as the last action.
+    /// Upgrades are sent as legacy async calls by the VM, there is no synchronous way to upgrade from source,
+    /// and the code of the source cannot be read to send the upgrade as a promise call instead.
+    /// A migration should be passed as the upgrade arguments, since the upgrade endpoint of the contract runs in the same call.
+    /// Other follow-up calls go in a separate batch, once `isBatchSuccessful` confirms the upgrade.
+    fn require_valid_batch_action_type(&self, action: &Action<Self::Api>, is_last_action: bool) {
+        require!(!action.is_nothing(), "Invalid action");
         require!(
-            !action.is_nothing() && !action.is_async_call() && !action.is_sc_upgrade(),
-            "Invalid action"
+            !action.ends_execution() || is_last_action,
+            "async calls and upgrades can only be the last action of a batch"
         );
     }
 
@@ -92,10 +129,6 @@ pub trait ProposeModule:
                     "proposed action has no effect"
                 );
 
-                if cfg!(debug_assertions) {
-                    return;
-                }
-
                 let other_sc_shard = self.blockchain().get_shard_of_address(&call_data.to);
                 require!(
                     own_shard == other_sc_shard,
@@ -105,16 +138,30 @@ pub trait ProposeModule:
             Action::SendTransferExecuteEsdt(call_data) => {
                 require!(!call_data.tokens.is_empty(), "No tokens to transfer");
 
-                if cfg!(debug_assertions) {
-                    return;
-                }
-
                 let other_sc_shard = self.blockchain().get_shard_of_address(&call_data.to);
                 require!(
                     own_shard == other_sc_shard,
                     ALL_TRANSFER_EXEC_SAME_SHARD_ERR_MSG
                 );
             }
+            // promise calls can target any shard
+            Action::SendPromiseCallEgld(call_data) => {
+                require!(
+                    call_data.egld_amount > 0 || !call_data.endpoint_name.is_empty(),
+                    "proposed action has no effect"
+                );
+                require!(
+                    call_data.opt_gas_limit.is_some(),
+                    PROMISE_CALL_GAS_LIMIT_REQUIRED_ERR_MSG
+                );
+            }
+            Action::SendPromiseCallEsdt(call_data) => {
+                require!(!call_data.tokens.is_empty(), "No tokens to transfer");
+                require!(
+                    call_data.opt_gas_limit.is_some(),
+                    PROMISE_CALL_GAS_LIMIT_REQUIRED_ERR_MSG
+                );
+            }
             _ => {}
         }
     }
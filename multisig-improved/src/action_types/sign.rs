@@ -17,13 +17,23 @@ pub trait SignModule:
     fn unsign_action(&self, action_id: ActionId, caller_id: AddressId) {
         self.require_action_exists(action_id);
 
-        let _ = self.action_signer_ids(action_id).swap_remove(&caller_id);
+        self.remove_action_signer(action_id, caller_id);
+        self.record_member_activity(caller_id);
+        self.clear_quorum_reached_if_lost(action_id);
     }
 
     fn add_signatures(&self, action_id: ActionId, board_members: &ManagedVec<AddressId>) {
-        let mut mapper = self.action_signer_ids(action_id);
+        self.require_action_not_expired(action_id);
+
+        let quorum_reached_before = self.quorum_reached(action_id);
         for board_member in board_members {
-            let _ = mapper.insert(board_member);
+            self.add_action_signer(action_id, board_member);
+            self.record_member_activity(board_member);
+        }
+
+        if !board_members.is_empty() {
+            self.record_board_activity();
         }
+        self.record_quorum_reached(action_id, quorum_reached_before);
     }
 }
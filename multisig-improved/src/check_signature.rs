@@ -1,8 +1,8 @@
 use multiversx_sc::api::SHA256_RESULT_LEN;
 
 use crate::common_types::{
-    action::{Action, ActionId, GroupId, Nonce},
-    signature::{ActionType, ItemToSign, Signature, SignatureArg},
+    action::{Action, ActionId, Deadline, GroupId, Nonce},
+    signature::{ActionType, ItemToSign, Signature, SignatureArg, SignatureType},
 };
 
 multiversx_sc::imports!();
@@ -17,12 +17,17 @@ pub trait CheckSignatureModule:
     fn check_proposal_signature(
         &self,
         action: &Action<Self::Api>,
+        opt_deadline: &Option<Deadline>,
         signature: SignatureArg<Self::Api>,
     ) {
         let mut multi_arg = MultiValueEncoded::new();
         multi_arg.push(signature);
 
-        let _ = self.check_sig_common(ActionType::Propose, ItemToSign::Propose(action), multi_arg);
+        let _ = self.check_sig_common(
+            ActionType::Propose,
+            ItemToSign::Propose(action, opt_deadline),
+            multi_arg,
+        );
     }
 
     fn check_single_action_signatures(
@@ -90,9 +95,12 @@ pub trait CheckSignatureModule:
         item_to_sign: ItemToSign<Self::Api>,
     ) {
         let bytes_to_sign = match item_to_sign {
-            ItemToSign::Propose(action) => {
-                self.serialize_and_hash_proposal(action, &sig_arg.user_address, sig_arg.nonce)
-            }
+            ItemToSign::Propose(action, opt_deadline) => self.serialize_and_hash_proposal(
+                action,
+                opt_deadline,
+                &sig_arg.user_address,
+                sig_arg.nonce,
+            ),
             ItemToSign::Action(action) => {
                 self.serialize_and_hash_action(action, &sig_arg.user_address, sig_arg.nonce)
             }
@@ -100,21 +108,60 @@ pub trait CheckSignatureModule:
                 self.serialize_and_hash_group(group_id, &sig_arg.user_address, sig_arg.nonce)
             }
         };
+        let public_key = self.get_signing_key(&sig_arg.user_address, sig_arg.signature_type);
         let signature_struct = Signature {
             signature_type: sig_arg.signature_type,
             raw_sig_bytes: sig_arg.raw_sig_bytes,
         };
-        signature_struct
-            .check_signature_by_type(&sig_arg.user_address, bytes_to_sign.as_managed_buffer());
+        signature_struct.check_signature_by_type(&public_key, bytes_to_sign.as_managed_buffer());
     }
 
+    fn get_signing_key(
+        &self,
+        user_address: &ManagedAddress,
+        signature_type: SignatureType,
+    ) -> ManagedBuffer {
+        if !signature_type.accepts_registered_key() {
+            return user_address.as_managed_buffer().clone();
+        }
+
+        let user_id = self.user_ids().get_id_non_zero(user_address);
+        let key_mapper = self.user_signing_key(user_id, signature_type);
+        if !key_mapper.is_empty() {
+            return key_mapper.get();
+        }
+
+        require!(
+            !signature_type.requires_registered_key(),
+            "No signing key registered"
+        );
+
+        user_address.as_managed_buffer().clone()
+    }
+
+    /// The deadline is signed as well, so whoever submits the proposal cannot change it.
     fn serialize_and_hash_proposal(
         &self,
         action: &Action<Self::Api>,
+        opt_deadline: &Option<Deadline>,
         signer: &ManagedAddress,
         user_nonce: Nonce,
     ) -> ManagedByteArray<SHA256_RESULT_LEN> {
-        self.serialize_and_hash_action_common(action, signer, user_nonce, ActionType::Propose)
+        let mut all_data = self.serialize_action_common(action, signer, user_nonce);
+
+        let deadline_encode_result = opt_deadline.dep_encode(&mut all_data);
+        require!(
+            deadline_encode_result.is_ok(),
+            "Error encoding deadline to buffer"
+        );
+
+        let action_type_encode_result = ActionType::Propose.dep_encode(&mut all_data);
+        require!(
+            action_type_encode_result.is_ok(),
+            ENCODING_ACTION_TYPE_ERR_MSG
+        );
+
+        self.crypto().sha256(all_data)
     }
 
     fn serialize_and_hash_action(
@@ -123,16 +170,23 @@ pub trait CheckSignatureModule:
         signer: &ManagedAddress,
         user_nonce: Nonce,
     ) -> ManagedByteArray<SHA256_RESULT_LEN> {
-        self.serialize_and_hash_action_common(action, signer, user_nonce, ActionType::SimpleAction)
+        let mut all_data = self.serialize_action_common(action, signer, user_nonce);
+
+        let action_type_encode_result = ActionType::SimpleAction.dep_encode(&mut all_data);
+        require!(
+            action_type_encode_result.is_ok(),
+            ENCODING_ACTION_TYPE_ERR_MSG
+        );
+
+        self.crypto().sha256(all_data)
     }
 
-    fn serialize_and_hash_action_common(
+    fn serialize_action_common(
         &self,
         action: &Action<Self::Api>,
         signer: &ManagedAddress,
         user_nonce: Nonce,
-        action_type: ActionType,
-    ) -> ManagedByteArray<SHA256_RESULT_LEN> {
+    ) -> ManagedBuffer {
         let mut all_data = signer.as_managed_buffer().clone();
 
         let nonce_encode_result = user_nonce.dep_encode(&mut all_data);
@@ -144,13 +198,7 @@ pub trait CheckSignatureModule:
             "Error encoding action to buffer"
         );
 
-        let action_type_encode_result = action_type.dep_encode(&mut all_data);
-        require!(
-            action_type_encode_result.is_ok(),
-            ENCODING_ACTION_TYPE_ERR_MSG
-        );
-
-        self.crypto().sha256(all_data)
+        all_data
     }
 
     fn serialize_and_hash_group(
@@ -1,18 +1,34 @@
 use crate::common_types::{
-    action::{ActionId, Nonce},
+    action::{Action, ActionId, ActionKind, Deadline, GroupId, Nonce, Timestamp, Weight},
+    board::MemberActivity,
     user_role::UserRole,
 };
 
 multiversx_sc::imports!();
 
+pub const DEFAULT_WEIGHT: Weight = 1;
+
 #[multiversx_sc::module]
 pub trait CommonFunctionsModule: crate::state::StateModule {
-    /// Returns `true` (`1`) if `getActionValidSignerCount >= getQuorum`.
+    /// Returns `true` (`1`) if `getActionValidSignerWeight >= getQuorum`.
     #[view(quorumReached)]
     fn quorum_reached(&self, action_id: ActionId) -> bool {
         let quorum = self.quorum_for_action(action_id).get();
-        let valid_signers_count = self.get_action_valid_signer_count(action_id);
-        valid_signers_count >= quorum
+        let valid_signers_weight = self.get_action_valid_signer_weight(action_id);
+        valid_signers_weight >= quorum
+    }
+
+    /// The quorum copied into new actions of the given type.
+    /// Unpausing requires the whole board.
+    fn get_quorum_for_action_kind(&self, action_kind: ActionKind) -> usize {
+        if action_kind == ActionKind::Unpause {
+            return self.total_board_weight().get();
+        }
+
+        match self.action_kind_quorum().get(&action_kind) {
+            Some(quorum) => quorum,
+            None => self.quorum().get(),
+        }
     }
 
     fn get_action_valid_signer_count(&self, action_id: ActionId) -> usize {
@@ -26,6 +42,27 @@ pub trait CommonFunctionsModule: crate::state::StateModule {
             .count()
     }
 
+    fn get_action_valid_signer_weight(&self, action_id: ActionId) -> Weight {
+        let signer_ids = self.action_signer_ids(action_id);
+        signer_ids
+            .iter()
+            .filter(|signer_id| {
+                let signer_role = self.user_id_to_role(*signer_id).get();
+                signer_role.can_sign()
+            })
+            .map(|signer_id| self.get_user_weight(signer_id))
+            .sum()
+    }
+
+    fn get_user_weight(&self, user_id: AddressId) -> Weight {
+        let mapper = self.user_weight(user_id);
+        if mapper.is_empty() {
+            DEFAULT_WEIGHT
+        } else {
+            mapper.get()
+        }
+    }
+
     fn get_action_signers(&self, action_id: ActionId) -> ManagedVec<ManagedAddress> {
         let signer_ids = self.action_signer_ids(action_id);
         let mut signers = ManagedVec::new();
@@ -62,10 +99,226 @@ pub trait CommonFunctionsModule: crate::state::StateModule {
         output_nonce
     }
 
+    /// Called whenever a user proposes, signs, unsigns or performs an action.
+    fn record_member_activity(&self, user_id: AddressId) {
+        self.member_activity(user_id).set(MemberActivity {
+            timestamp: self.blockchain().get_block_timestamp(),
+            round: self.blockchain().get_block_round(),
+        });
+    }
+
+    /// Called whenever a board member signs, which also cancels any pending recovery.
+    fn record_board_activity(&self) {
+        let current_timestamp = self.blockchain().get_block_timestamp();
+        self.last_board_activity().set(current_timestamp);
+    }
+
+    /// Applies to both proposing and performing actions, including the ones approved by modules.
+    fn require_action_allowed_if_paused(&self, action: &Action<Self::Api>) {
+        if self.paused().get() {
+            require!(action.is_allowed_while_paused(), "contract is paused");
+        }
+    }
+
+    /// The quorum can be changed while paused, e.g. by reconfiguring the board,
+    /// so unpausing requires the whole board at the time it is performed.
+    fn require_whole_board_for_unpause(&self, action_id: ActionId, action: &Action<Self::Api>) {
+        if matches!(action, Action::Unpause) {
+            require!(
+                self.get_action_valid_signer_weight(action_id) >= self.total_board_weight().get(),
+                "unpause requires the whole board"
+            );
+        }
+    }
+
+    fn require_storage_migrated(&self) {
+        require!(
+            !self.storage_migration_pending().get(),
+            "storage migration pending"
+        );
+    }
+
     fn require_action_exists(&self, action_id: ActionId) {
         require!(
             !self.action_mapper().item_is_empty_unchecked(action_id),
             "action does not exist"
         );
     }
+
+    fn require_action_not_expired(&self, action_id: ActionId) {
+        require!(!self.is_action_expired(action_id), "action expired");
+    }
+
+    fn is_action_expired(&self, action_id: ActionId) -> bool {
+        match self.action_deadline(action_id).get() {
+            Some(deadline) => self.is_deadline_passed(&deadline),
+            None => false,
+        }
+    }
+
+    /// Called every time signatures are added to the action, with the quorum status from before.
+    /// A timestamp left over from before the quorum was lost is replaced.
+    fn record_quorum_reached(&self, action_id: ActionId, quorum_reached_before: bool) {
+        if !quorum_reached_before && self.quorum_reached(action_id) {
+            self.quorum_reached_timestamp(action_id)
+                .set(Some(self.blockchain().get_block_timestamp()));
+        }
+    }
+
+    /// Keeps `action_signer_ids` and `user_signed_actions` in sync.
+    fn add_action_signer(&self, action_id: ActionId, user_id: AddressId) {
+        let _ = self.action_signer_ids(action_id).insert(user_id);
+        let _ = self.user_signed_actions(user_id).insert(action_id);
+    }
+
+    fn remove_action_signer(&self, action_id: ActionId, user_id: AddressId) {
+        let _ = self.action_signer_ids(action_id).swap_remove(&user_id);
+        let _ = self.user_signed_actions(user_id).swap_remove(&action_id);
+    }
+
+    fn clear_action(&self, action_id: ActionId) {
+        self.action_mapper().clear_entry_unchecked(action_id);
+        let mut signer_ids = self.action_signer_ids(action_id);
+        for signer_id in signer_ids.iter() {
+            let _ = self.user_signed_actions(signer_id).swap_remove(&action_id);
+        }
+        signer_ids.clear();
+        self.action_deadline(action_id).clear();
+        self.quorum_reached_timestamp(action_id).clear();
+        self.async_call_retry(action_id).clear();
+
+        let group_id = self.group_for_action(action_id).take();
+        if group_id != 0 {
+            let mut group_mapper = self.action_groups(group_id);
+            let _ = group_mapper.swap_remove(&action_id);
+            if group_mapper.is_empty() {
+                self.action_group_order(group_id).clear();
+            }
+        }
+    }
+
+    /// Pending actions of the batch, in the order they were proposed.
+    /// Batches proposed before the order was recorded fall back to the storage order.
+    fn get_group_actions_in_order(&self, group_id: GroupId) -> ManagedVec<ActionId> {
+        let group_mapper = self.action_groups(group_id);
+        let order_mapper = self.action_group_order(group_id);
+        if order_mapper.is_empty() {
+            return group_mapper.iter().collect();
+        }
+
+        let mut result = ManagedVec::new();
+        for action_id in order_mapper.iter() {
+            if group_mapper.contains(&action_id) {
+                result.push(action_id);
+            }
+        }
+
+        result
+    }
+
+    /// A batch reaches quorum once all its actions have reached quorum.
+    fn group_quorum_reached(&self, group_id: GroupId) -> bool {
+        self.action_groups(group_id)
+            .iter()
+            .all(|action_id| self.quorum_reached(action_id))
+    }
+
+    /// Same as `record_quorum_reached`, for the batch as a whole.
+    fn record_group_quorum_reached(&self, group_id: GroupId, quorum_reached_before: bool) {
+        if !quorum_reached_before && self.group_quorum_reached(group_id) {
+            self.group_quorum_reached_timestamp(group_id)
+                .set(Some(self.blockchain().get_block_timestamp()));
+        }
+    }
+
+    /// Quorum can also be lost and regained by changing the role or weight of the signers,
+    /// so the delay restarts whenever that happens.
+    /// Actions without a recorded timestamp had quorum before the timestamps were introduced.
+    fn get_quorum_reached_timestamp(&self, action_id: ActionId) -> Option<Timestamp> {
+        let group_id = self.group_for_action(action_id).get();
+        let (opt_recorded_timestamp, action_ids) = if group_id != 0 {
+            let action_ids: ManagedVec<ActionId> = self.action_groups(group_id).iter().collect();
+            (
+                self.group_quorum_reached_timestamp(group_id).get(),
+                action_ids,
+            )
+        } else {
+            (
+                self.quorum_reached_timestamp(action_id).get(),
+                ManagedVec::from_single_item(action_id),
+            )
+        };
+
+        let mut timestamp =
+            opt_recorded_timestamp.unwrap_or_else(|| self.quorum_tracking_start_timestamp().get());
+        for action_id in &action_ids {
+            if !self.quorum_reached(action_id) {
+                return None;
+            }
+
+            for signer_id in self.action_signer_ids(action_id).iter() {
+                let changed_timestamp = self.signing_power_changed_timestamp(signer_id).get();
+                timestamp = core::cmp::max(timestamp, changed_timestamp);
+            }
+        }
+
+        Some(timestamp)
+    }
+
+    /// Called when signatures are removed, so the delay is counted again once quorum is reached anew.
+    fn clear_quorum_reached_if_lost(&self, action_id: ActionId) {
+        if self.quorum_reached(action_id) {
+            return;
+        }
+
+        self.quorum_reached_timestamp(action_id).clear();
+
+        let group_id = self.group_for_action(action_id).get();
+        if group_id != 0 {
+            self.group_quorum_reached_timestamp(group_id).clear();
+        }
+    }
+
+    fn get_execution_delay(&self, action_kind: ActionKind) -> Timestamp {
+        match self.action_kind_execution_delay(action_kind).get() {
+            Some(delay) => delay,
+            None => self.execution_delay().get(),
+        }
+    }
+
+    fn get_earliest_execution_time(&self, action_id: ActionId) -> Option<Timestamp> {
+        let opt_quorum_reached_timestamp = self.get_quorum_reached_timestamp(action_id);
+
+        let action_kind = self.action_mapper().get(action_id).kind();
+        opt_quorum_reached_timestamp
+            .map(|timestamp| timestamp + self.get_execution_delay(action_kind))
+    }
+
+    fn require_execution_delay_passed(&self, action_id: ActionId) {
+        require!(
+            self.is_execution_delay_passed(action_id),
+            "execution delay has not passed"
+        );
+    }
+
+    fn is_execution_delay_passed(&self, action_id: ActionId) -> bool {
+        let action_kind = self.action_mapper().get(action_id).kind();
+        if self.get_execution_delay(action_kind) == 0 {
+            return true;
+        }
+
+        match self.get_earliest_execution_time(action_id) {
+            Some(earliest_execution_time) => {
+                self.blockchain().get_block_timestamp() >= earliest_execution_time
+            }
+            None => false,
+        }
+    }
+
+    fn is_deadline_passed(&self, deadline: &Deadline) -> bool {
+        match *deadline {
+            Deadline::Timestamp(timestamp) => self.blockchain().get_block_timestamp() > timestamp,
+            Deadline::Round(round) => self.blockchain().get_block_round() > round,
+        }
+    }
 }
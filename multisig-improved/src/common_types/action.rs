@@ -5,6 +5,10 @@ multiversx_sc::derive_imports!();
 
 pub type GasLimit = u64;
 pub type Nonce = u64;
+pub type Timestamp = u64;
+pub type Round = u64;
+
+pub type Weight = usize;
 
 pub type ActionId = usize;
 pub type GroupId = usize;
@@ -17,6 +21,63 @@ pub enum ActionStatus {
     Aborted,
 }
 
+/// Result of a call sent through the promises API, as recorded by its callback.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone, Copy, Debug,
+)]
+pub enum PromiseCallStatus {
+    None,
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+/// Outcome of an async call, upgrade or promise call, as recorded by its callback.
+/// The error code and message are only set for failed calls, with the message cut to 64 bytes.
+/// The hash of the returned data is only set for successful ones: the SHA-256 of the returned values,
+/// each encoded as its length (4 bytes, big endian) followed by its bytes.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone)]
+pub struct ActionExecutionResult<M: ManagedTypeApi> {
+    pub success: bool,
+    pub err_code: u32,
+    pub err_message: ManagedBuffer<M>,
+    pub return_data_hash: ManagedByteArray<M, 32>,
+}
+
+/// Async call kept in storage after being performed, so it can be performed again if it fails.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone, Copy, Debug,
+)]
+pub struct AsyncCallRetryInfo {
+    /// Number of times the call was sent, including the first one
+    pub attempts: usize,
+    /// The callback was not received yet
+    pub in_progress: bool,
+}
+
+/// Outcome of a performed batch.
+/// Batches ending with an async call or an upgrade, or containing promise calls,
+/// only complete once all their callbacks were received.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone, Copy, Debug,
+)]
+pub enum GroupExecutionStatus {
+    NotPerformed,
+    AsyncCallPending,
+    Succeeded,
+    Failed,
+    PromiseCallsPending,
+}
+
+/// Last block timestamp or round in which the action can still be signed and performed
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone, Copy, Debug,
+)]
+pub enum Deadline {
+    Timestamp(Timestamp),
+    Round(Round),
+}
+
 #[derive(NestedEncode, NestedDecode, TypeAbi, Clone)]
 pub struct CallActionData<M: ManagedTypeApi> {
     pub to: ManagedAddress<M>,
@@ -60,6 +121,101 @@ pub enum Action<M: ManagedTypeApi> {
     },
     AddModule(ManagedAddress<M>),
     RemoveModule(ManagedAddress<M>),
+    SetExecutionDelay(Timestamp),
+    SetActionKindExecutionDelay {
+        action_kind: ActionKind,
+        opt_delay: Option<Timestamp>,
+    },
+    SetBoardMemberWeight {
+        board_member: ManagedAddress<M>,
+        weight: Weight,
+    },
+    ChangeActionKindQuorum {
+        action_kind: ActionKind,
+        opt_quorum: Option<usize>,
+    },
+    SetModuleNotifications {
+        sc_address: ManagedAddress<M>,
+        enabled: bool,
+    },
+    SetModuleActionApproval {
+        sc_address: ManagedAddress<M>,
+        enabled: bool,
+    },
+    AddGuardian(ManagedAddress<M>),
+    AddExecutor(ManagedAddress<M>),
+    Unpause,
+    SetRecoveryConfig {
+        recovery_addresses: ManagedVec<M, ManagedAddress<M>>,
+        challenge_period: Timestamp,
+    },
+    ReplaceBoardMember {
+        old_board_member: ManagedAddress<M>,
+        new_board_member: ManagedAddress<M>,
+        migrate_signatures: bool,
+    },
+    ReconfigureBoard {
+        add: ManagedVec<M, ManagedAddress<M>>,
+        remove: ManagedVec<M, ManagedAddress<M>>,
+        proposers_add: ManagedVec<M, ManagedAddress<M>>,
+        proposers_remove: ManagedVec<M, ManagedAddress<M>>,
+        new_quorum: usize,
+    },
+    RemoveProposer(ManagedAddress<M>),
+    DemoteToProposer(ManagedAddress<M>),
+    PromoteToBoardMember(ManagedAddress<M>),
+    ChangeOwner(ManagedAddress<M>),
+    ChangeManagedContractOwner {
+        sc_address: ManagedAddress<M>,
+        new_owner: ManagedAddress<M>,
+    },
+    SetMaxBoardMembers(usize),
+    SetMaxModules(usize),
+    SendPromiseCallEgld(CallActionData<M>),
+    SendPromiseCallEsdt(EsdtTransferExecuteData<M>),
+    SetMaxAsyncCallRetries(usize),
+}
+
+/// Identifies the type of an `Action`, without its arguments.
+/// Note: Always add new kinds at the end, in the same order as the `Action` variants.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Eq, Clone, Copy, Debug,
+)]
+pub enum ActionKind {
+    Nothing,
+    AddBoardMember,
+    AddProposer,
+    RemoveUser,
+    ChangeQuorum,
+    SendTransferExecuteEgld,
+    SendTransferExecuteEsdt,
+    SendAsyncCall,
+    SCDeployFromSource,
+    SCUpgradeFromSource,
+    AddModule,
+    RemoveModule,
+    SetExecutionDelay,
+    SetActionKindExecutionDelay,
+    SetBoardMemberWeight,
+    ChangeActionKindQuorum,
+    SetModuleNotifications,
+    SetModuleActionApproval,
+    AddGuardian,
+    AddExecutor,
+    Unpause,
+    SetRecoveryConfig,
+    ReplaceBoardMember,
+    ReconfigureBoard,
+    RemoveProposer,
+    DemoteToProposer,
+    PromoteToBoardMember,
+    ChangeOwner,
+    ChangeManagedContractOwner,
+    SetMaxBoardMembers,
+    SetMaxModules,
+    SendPromiseCallEgld,
+    SendPromiseCallEsdt,
+    SetMaxAsyncCallRetries,
 }
 
 impl<M: ManagedTypeApi> Action<M> {
@@ -70,6 +226,75 @@ impl<M: ManagedTypeApi> Action<M> {
         !matches!(*self, Action::Nothing)
     }
 
+    pub fn kind(&self) -> ActionKind {
+        match self {
+            Action::Nothing => ActionKind::Nothing,
+            Action::AddBoardMember(_) => ActionKind::AddBoardMember,
+            Action::AddProposer(_) => ActionKind::AddProposer,
+            Action::RemoveUser(_) => ActionKind::RemoveUser,
+            Action::ChangeQuorum(_) => ActionKind::ChangeQuorum,
+            Action::SendTransferExecuteEgld(_) => ActionKind::SendTransferExecuteEgld,
+            Action::SendTransferExecuteEsdt(_) => ActionKind::SendTransferExecuteEsdt,
+            Action::SendAsyncCall(_) => ActionKind::SendAsyncCall,
+            Action::SCDeployFromSource(_) => ActionKind::SCDeployFromSource,
+            Action::SCUpgradeFromSource { .. } => ActionKind::SCUpgradeFromSource,
+            Action::AddModule(_) => ActionKind::AddModule,
+            Action::RemoveModule(_) => ActionKind::RemoveModule,
+            Action::SetExecutionDelay(_) => ActionKind::SetExecutionDelay,
+            Action::SetActionKindExecutionDelay { .. } => ActionKind::SetActionKindExecutionDelay,
+            Action::SetBoardMemberWeight { .. } => ActionKind::SetBoardMemberWeight,
+            Action::ChangeActionKindQuorum { .. } => ActionKind::ChangeActionKindQuorum,
+            Action::SetModuleNotifications { .. } => ActionKind::SetModuleNotifications,
+            Action::SetModuleActionApproval { .. } => ActionKind::SetModuleActionApproval,
+            Action::AddGuardian(_) => ActionKind::AddGuardian,
+            Action::AddExecutor(_) => ActionKind::AddExecutor,
+            Action::Unpause => ActionKind::Unpause,
+            Action::SetRecoveryConfig { .. } => ActionKind::SetRecoveryConfig,
+            Action::ReplaceBoardMember { .. } => ActionKind::ReplaceBoardMember,
+            Action::ReconfigureBoard { .. } => ActionKind::ReconfigureBoard,
+            Action::RemoveProposer(_) => ActionKind::RemoveProposer,
+            Action::DemoteToProposer(_) => ActionKind::DemoteToProposer,
+            Action::PromoteToBoardMember(_) => ActionKind::PromoteToBoardMember,
+            Action::ChangeOwner(_) => ActionKind::ChangeOwner,
+            Action::ChangeManagedContractOwner { .. } => ActionKind::ChangeManagedContractOwner,
+            Action::SetMaxBoardMembers(_) => ActionKind::SetMaxBoardMembers,
+            Action::SetMaxModules(_) => ActionKind::SetMaxModules,
+            Action::SendPromiseCallEgld(_) => ActionKind::SendPromiseCallEgld,
+            Action::SendPromiseCallEsdt(_) => ActionKind::SendPromiseCallEsdt,
+            Action::SetMaxAsyncCallRetries(_) => ActionKind::SetMaxAsyncCallRetries,
+        }
+    }
+
+    /// Modules may only approve calls to other contracts, never changes to the multisig itself.
+    pub fn is_external_call(&self) -> bool {
+        matches!(
+            *self,
+            Action::SendTransferExecuteEgld(_)
+                | Action::SendTransferExecuteEsdt(_)
+                | Action::SendAsyncCall(_)
+                | Action::SCDeployFromSource(_)
+                | Action::SCUpgradeFromSource { .. }
+                | Action::SendPromiseCallEgld(_)
+                | Action::SendPromiseCallEsdt(_)
+        )
+    }
+
+    /// While paused, the board can only be rotated and the contract unpaused.
+    /// Keep the list in the README in sync.
+    pub fn is_allowed_while_paused(&self) -> bool {
+        matches!(
+            *self,
+            Action::AddBoardMember(_)
+                | Action::RemoveUser(_)
+                | Action::ReplaceBoardMember { .. }
+                | Action::ReconfigureBoard { .. }
+                | Action::RemoveProposer(_)
+                | Action::DemoteToProposer(_)
+                | Action::PromoteToBoardMember(_)
+                | Action::Unpause
+        )
+    }
+
     pub fn is_nothing(&self) -> bool {
         matches!(*self, Action::Nothing)
     }
@@ -87,26 +312,82 @@ impl<M: ManagedTypeApi> Action<M> {
             }
         )
     }
+
+    pub fn is_promise_call(&self) -> bool {
+        matches!(
+            *self,
+            Action::SendPromiseCallEgld(_) | Action::SendPromiseCallEsdt(_)
+        )
+    }
+
+    /// Upgrades are performed through an async call as well.
+    pub fn ends_execution(&self) -> bool {
+        self.is_async_call() || self.is_sc_upgrade()
+    }
 }
 
 /// Not used internally, just to retrieve results via endpoint.
-#[derive(TopEncode, TypeAbi)]
+#[derive(TopEncode, TopDecode, TypeAbi)]
 pub struct ActionFullInfo<M: ManagedTypeApi> {
     pub action_id: ActionId,
     pub group_id: GroupId,
     pub action_data: Action<M>,
     pub signers: ManagedVec<M, ManagedAddress<M>>,
+    pub deadline: Option<Deadline>,
+}
+
+/// Action executed without signatures, because a module approved it.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct ModuleExecutionInfo<M: ManagedTypeApi> {
+    pub action_id: ActionId,
+    pub module_address: ManagedAddress<M>,
+    pub proposer: ManagedAddress<M>,
+    pub timestamp: Timestamp,
 }
 
 #[cfg(test)]
 mod test {
+    use multiversx_sc::types::{BigUint, ManagedAddress, ManagedBuffer, ManagedVec};
     use multiversx_sc_scenario::api::StaticApi;
 
-    use super::Action;
+    use super::{Action, ActionKind, CallActionData};
 
     #[test]
     fn test_is_pending() {
         assert!(!Action::<StaticApi>::Nothing.is_pending());
         assert!(Action::<StaticApi>::ChangeQuorum(5).is_pending());
     }
+
+    #[test]
+    fn test_kind() {
+        assert_eq!(Action::<StaticApi>::Nothing.kind(), ActionKind::Nothing);
+        assert_eq!(
+            Action::<StaticApi>::ChangeQuorum(5).kind(),
+            ActionKind::ChangeQuorum
+        );
+        assert_eq!(
+            Action::<StaticApi>::SetActionKindExecutionDelay {
+                action_kind: ActionKind::ChangeQuorum,
+                opt_delay: None,
+            }
+            .kind(),
+            ActionKind::SetActionKindExecutionDelay
+        );
+    }
+
+    #[test]
+    fn test_is_external_call() {
+        assert!(!Action::<StaticApi>::Nothing.is_external_call());
+        assert!(!Action::<StaticApi>::ChangeQuorum(5).is_external_call());
+        assert!(!Action::<StaticApi>::SetExecutionDelay(60).is_external_call());
+
+        let call_data = CallActionData {
+            to: ManagedAddress::zero(),
+            egld_amount: BigUint::from(1u32),
+            opt_gas_limit: None,
+            endpoint_name: ManagedBuffer::new(),
+            arguments: ManagedVec::new(),
+        };
+        assert!(Action::<StaticApi>::SendAsyncCall(call_data).is_external_call());
+    }
 }
@@ -0,0 +1,21 @@
+use crate::common_types::action::{Round, Timestamp, Weight};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// Moment of the last propose, sign, unsign or perform of a user.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug,
+)]
+pub struct MemberActivity {
+    pub timestamp: Timestamp,
+    pub round: Round,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct BoardMemberOverview<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub weight: Weight,
+    /// Empty if the board member never interacted with the contract.
+    pub last_activity: Option<MemberActivity>,
+}
@@ -1,3 +1,8 @@
 pub mod action;
+pub mod board;
+pub mod recovery;
+#[cfg(feature = "rust-vm-crypto")]
+pub mod rust_vm_crypto;
 pub mod signature;
 pub mod user_role;
+pub mod webauthn;
@@ -0,0 +1,14 @@
+use crate::common_types::action::Timestamp;
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// Board replacement proposed by one of the recovery addresses.
+/// Any board signature after `start_timestamp` cancels it.
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi)]
+pub struct PendingRecovery<M: ManagedTypeApi> {
+    pub initiator: ManagedAddress<M>,
+    pub new_board: ManagedVec<M, ManagedAddress<M>>,
+    pub new_quorum: usize,
+    pub start_timestamp: Timestamp,
+}
@@ -0,0 +1,34 @@
+//! Native signature checks, used instead of the VM hooks
+//! that are not available in the Rust testing framework.
+//! Never enabled when building the contract.
+
+pub fn verify_secp256r1(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+
+    let verifying_key = match VerifyingKey::from_sec1_bytes(public_key) {
+        Result::Ok(verifying_key) => verifying_key,
+        Result::Err(_) => return false,
+    };
+    let signature = match Signature::from_der(signature) {
+        Result::Ok(signature) => signature,
+        Result::Err(_) => return false,
+    };
+
+    verifying_key.verify(message, &signature).is_ok()
+}
+
+/// The message is already a hash, so it is checked as is, without hashing it again.
+pub fn verify_secp256k1(public_key: &[u8], message: &[u8], signature: &[u8]) -> bool {
+    use k256::ecdsa::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey};
+
+    let verifying_key = match VerifyingKey::from_sec1_bytes(public_key) {
+        Result::Ok(verifying_key) => verifying_key,
+        Result::Err(_) => return false,
+    };
+    let signature = match Signature::from_der(signature) {
+        Result::Ok(signature) => signature,
+        Result::Err(_) => return false,
+    };
+
+    verifying_key.verify_prehash(message, &signature).is_ok()
+}
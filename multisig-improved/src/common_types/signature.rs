@@ -1,6 +1,9 @@
 use multiversx_sc::api::{CryptoApi, CryptoApiImpl};
 
-use super::action::{Action, GroupId, Nonce};
+use super::{
+    action::{Action, Deadline, GroupId, Nonce},
+    webauthn::WebAuthnSignature,
+};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
@@ -14,8 +17,15 @@ pub struct SignatureArg<M: ManagedTypeApi> {
     pub raw_sig_bytes: ManagedBuffer<M>,
 }
 
+static SECP256K1_ERR_MSG: &[u8] = b"Failed checking Secp256k1 signature";
+
+pub const COMPRESSED_PUBLIC_KEY_LEN: usize = 33;
+pub const UNCOMPRESSED_PUBLIC_KEY_LEN: usize = 65;
+
 /// Note: Always add new signature types at the end, and NEVER delete any types.
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+#[derive(
+    TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Eq, Clone, Copy, Debug,
+)]
 pub enum SignatureType {
     Ed25519,
     Secp256r1,
@@ -31,7 +41,7 @@ pub enum ActionType {
 
 #[derive(Clone)]
 pub enum ItemToSign<'a, M: ManagedTypeApi> {
-    Propose(&'a Action<M>),
+    Propose(&'a Action<M>, &'a Option<Deadline>),
     Action(&'a Action<M>),
     Group(GroupId),
 }
@@ -50,33 +60,101 @@ pub struct Signature<M: ManagedTypeApi> {
     pub raw_sig_bytes: ManagedBuffer<M>,
 }
 
+impl SignatureType {
+    /// Keys that cannot be derived from the user address have to be registered beforehand.
+    pub fn requires_registered_key(&self) -> bool {
+        matches!(*self, SignatureType::Secp256r1)
+    }
+
+    /// Secp256k1 signers use their address as key, unless they registered one.
+    pub fn accepts_registered_key(&self) -> bool {
+        matches!(*self, SignatureType::Secp256r1 | SignatureType::Secp256k1)
+    }
+}
+
 impl<M: ManagedTypeApi + CryptoApi> Signature<M> {
+    /// `public_key` is either the user address,
+    /// or the SEC1 encoded key registered by the user for the given signature type.
     pub fn check_signature_by_type(
         &self,
-        user_address: &ManagedAddress<M>,
+        public_key: &ManagedBuffer<M>,
         bytes_to_sign: &ManagedBuffer<M>,
     ) {
-        if cfg!(debug_assertions) {
-            return;
-        }
-
         match self.signature_type {
             SignatureType::Ed25519 => M::crypto_api_impl().verify_ed25519_managed(
-                user_address.as_managed_buffer().get_handle(),
+                public_key.get_handle(),
                 bytes_to_sign.get_handle(),
                 self.raw_sig_bytes.get_handle(),
             ),
-            SignatureType::Secp256r1 => todo!(), // not implemented yet
+            SignatureType::Secp256r1 => {
+                let webauthn_sig = WebAuthnSignature::<M>::decode_from_raw(&self.raw_sig_bytes);
+                let signed_message = webauthn_sig.build_signed_message(bytes_to_sign);
+                verify_secp256r1::<M>(public_key, &signed_message, &webauthn_sig.signature);
+            }
             SignatureType::Secp256k1 => {
-                let verify_result = M::crypto_api_impl().verify_secp256k1_managed(
-                    user_address.as_managed_buffer().get_handle(),
-                    bytes_to_sign.get_handle(),
-                    self.raw_sig_bytes.get_handle(),
-                );
-                if !verify_result {
-                    M::error_api_impl().signal_error(b"Failed checking Secp256k1 signature");
-                }
+                verify_secp256k1::<M>(public_key, bytes_to_sign, &self.raw_sig_bytes);
             }
         }
     }
 }
+
+#[cfg(not(feature = "rust-vm-crypto"))]
+fn verify_secp256r1<M: ManagedTypeApi + CryptoApi>(
+    public_key: &ManagedBuffer<M>,
+    message: &ManagedBuffer<M>,
+    signature: &ManagedBuffer<M>,
+) {
+    M::crypto_api_impl().verify_secp256r1_managed(
+        public_key.get_handle(),
+        message.get_handle(),
+        signature.get_handle(),
+    );
+}
+
+#[cfg(feature = "rust-vm-crypto")]
+fn verify_secp256r1<M: ManagedTypeApi + CryptoApi>(
+    public_key: &ManagedBuffer<M>,
+    message: &ManagedBuffer<M>,
+    signature: &ManagedBuffer<M>,
+) {
+    let valid = super::rust_vm_crypto::verify_secp256r1(
+        public_key.to_boxed_bytes().as_slice(),
+        message.to_boxed_bytes().as_slice(),
+        signature.to_boxed_bytes().as_slice(),
+    );
+    if !valid {
+        M::error_api_impl().signal_error(b"Failed checking Secp256r1 signature");
+    }
+}
+
+#[cfg(not(feature = "rust-vm-crypto"))]
+fn verify_secp256k1<M: ManagedTypeApi + CryptoApi>(
+    public_key: &ManagedBuffer<M>,
+    message: &ManagedBuffer<M>,
+    signature: &ManagedBuffer<M>,
+) {
+    let verify_result = M::crypto_api_impl().verify_secp256k1_managed(
+        public_key.get_handle(),
+        message.get_handle(),
+        signature.get_handle(),
+    );
+    if !verify_result {
+        M::error_api_impl().signal_error(SECP256K1_ERR_MSG);
+    }
+}
+
+#[cfg(feature = "rust-vm-crypto")]
+fn verify_secp256k1<M: ManagedTypeApi + CryptoApi>(
+    public_key: &ManagedBuffer<M>,
+    message: &ManagedBuffer<M>,
+    signature: &ManagedBuffer<M>,
+) {
+    let valid = super::rust_vm_crypto::verify_secp256k1(
+        public_key.to_boxed_bytes().as_slice(),
+        message.to_boxed_bytes().as_slice(),
+        signature.to_boxed_bytes().as_slice(),
+    );
+    if !valid {
+        M::error_api_impl().signal_error(SECP256K1_ERR_MSG);
+    }
+}
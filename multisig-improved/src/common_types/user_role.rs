@@ -1,4 +1,7 @@
-use crate::common_types::action::ActionId;
+use crate::{
+    common_functions::DEFAULT_WEIGHT,
+    common_types::{action::ActionId, signature::SignatureType},
+};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
@@ -8,6 +11,10 @@ pub enum UserRole {
     None,
     Proposer,
     BoardMember,
+    /// Can neither propose nor sign, only veto pending actions.
+    Guardian,
+    /// Can only perform actions that reached quorum, e.g. a relayer.
+    Executor,
 }
 
 impl UserRole {
@@ -16,7 +23,7 @@ impl UserRole {
     }
 
     pub fn can_perform_action(&self) -> bool {
-        self.can_propose()
+        self.can_propose() || matches!(*self, UserRole::Executor)
     }
 
     pub fn can_discard_action(&self) -> bool {
@@ -27,6 +34,14 @@ impl UserRole {
         matches!(*self, UserRole::BoardMember)
     }
 
+    pub fn can_veto(&self) -> bool {
+        matches!(*self, UserRole::Guardian)
+    }
+
+    pub fn can_pause(&self) -> bool {
+        matches!(*self, UserRole::BoardMember | UserRole::Guardian)
+    }
+
     pub fn has_no_role(&self) -> bool {
         matches!(*self, UserRole::None)
     }
@@ -52,7 +67,7 @@ impl UserRole {
     pub fn require_can_perform_action<M: ManagedTypeApi>(&self) {
         if !self.can_perform_action() {
             M::error_api_impl()
-                .signal_error(b"only board members and proposers can perform actions");
+                .signal_error(b"only board members, proposers and executors can perform actions");
         }
     }
 
@@ -62,6 +77,18 @@ impl UserRole {
                 .signal_error(b"only board members and proposers can discard actions");
         }
     }
+
+    pub fn require_can_veto<M: ManagedTypeApi>(&self) {
+        if !self.can_veto() {
+            M::error_api_impl().signal_error(b"only guardians can veto actions");
+        }
+    }
+
+    pub fn require_can_pause<M: ManagedTypeApi>(&self) {
+        if !self.can_pause() {
+            M::error_api_impl().signal_error(b"only board members and guardians can pause");
+        }
+    }
 }
 
 fn usize_add_isize(value: &mut usize, delta: isize) {
@@ -69,12 +96,15 @@ fn usize_add_isize(value: &mut usize, delta: isize) {
 }
 
 /// Can be used to:
-/// - create new user (board member / proposer)
-/// - remove user (board member / proposer)
+/// - create new user (board member / proposer / guardian / executor)
+/// - remove user (board member / proposer / guardian / executor)
 /// - reactivate removed user
-/// - convert between board member and proposer
+/// - convert between any of the roles
 ///
-/// Will keep the board size and proposer count in sync.
+/// Will keep the board size, proposer count and guardian count in sync.
+/// Users that are no longer board members lose their voting weight and their registered signing keys.
+/// Removed users also lose their recorded activity.
+/// Nothing changes for users that already have the new role.
 pub fn change_user_role<Sc: crate::state::StateModule + crate::external::events::EventsModule>(
     sc_ref: &Sc,
     action_id: ActionId,
@@ -99,14 +129,46 @@ pub fn change_user_role<Sc: crate::state::StateModule + crate::external::events:
 
     sc_ref.perform_change_user_event(action_id, &user_address, old_role, new_role);
 
+    // e.g. a board member added again keeps the weight and the signing keys
+    if old_role == new_role {
+        return;
+    }
+
+    if new_role == UserRole::None {
+        sc_ref.member_activity(user_id).clear();
+    }
+
+    if old_role.can_sign() || new_role.can_sign() {
+        let current_timestamp = sc_ref.blockchain().get_block_timestamp();
+        sc_ref
+            .signing_power_changed_timestamp(user_id)
+            .set(current_timestamp);
+    }
+
     // update board size
     let mut board_members_delta = 0isize;
+    let mut total_board_weight = sc_ref.total_board_weight().get();
     if old_role == UserRole::BoardMember {
         board_members_delta -= 1;
+        let _ = sc_ref.board_member_ids().swap_remove(&user_id);
+        let old_weight = sc_ref.user_weight(user_id).take();
+        total_board_weight -= if old_weight == 0 {
+            DEFAULT_WEIGHT
+        } else {
+            old_weight
+        };
+
+        // a member added back later has to register the keys again
+        for signature_type in [SignatureType::Secp256r1, SignatureType::Secp256k1] {
+            sc_ref.user_signing_key(user_id, signature_type).clear();
+        }
     }
     if new_role == UserRole::BoardMember {
         board_members_delta += 1;
+        let _ = sc_ref.board_member_ids().insert(user_id);
+        total_board_weight += DEFAULT_WEIGHT;
     }
+    sc_ref.total_board_weight().set(total_board_weight);
     if board_members_delta != 0 {
         sc_ref
             .num_board_members()
@@ -125,4 +187,17 @@ pub fn change_user_role<Sc: crate::state::StateModule + crate::external::events:
             .num_proposers()
             .update(|value| usize_add_isize(value, proposers_delta));
     }
+
+    let mut guardians_delta = 0isize;
+    if old_role == UserRole::Guardian {
+        guardians_delta -= 1;
+    }
+    if new_role == UserRole::Guardian {
+        guardians_delta += 1;
+    }
+    if guardians_delta != 0 {
+        sc_ref
+            .num_guardians()
+            .update(|value| usize_add_isize(value, guardians_delta));
+    }
 }
@@ -0,0 +1,244 @@
+use multiversx_sc::api::{CryptoApi, CryptoApiImpl, SHA256_RESULT_LEN};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub const MAX_AUTHENTICATOR_DATA_LEN: usize = 256;
+pub const MAX_CLIENT_DATA_JSON_LEN: usize = 512;
+pub const CHALLENGE_BASE64_LEN: usize = 43;
+
+/// 32 bytes RP ID hash, 1 byte flags, 4 bytes signature counter
+const AUTHENTICATOR_DATA_MIN_LEN: usize = 37;
+const AUTHENTICATOR_DATA_FLAGS_INDEX: usize = 32;
+const USER_PRESENT_FLAG: u8 = 0x01;
+
+static WEBAUTHN_GET_TYPE: &[u8] = br#""type":"webauthn.get""#;
+static CHALLENGE_KEY: &[u8] = br#""challenge":""#;
+static AUTH_DATA_TOO_SHORT_ERR_MSG: &[u8] = b"Authenticator data too short";
+static USER_NOT_PRESENT_ERR_MSG: &[u8] = b"WebAuthn user presence flag not set";
+static INVALID_CLIENT_DATA_TYPE_ERR_MSG: &[u8] = b"Invalid WebAuthn client data type";
+static CHALLENGE_MISSING_ERR_MSG: &[u8] = b"WebAuthn challenge missing";
+static CHALLENGE_MISMATCH_ERR_MSG: &[u8] = b"WebAuthn challenge mismatch";
+static BASE64_URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Envelope of a Secp256r1 signature produced by a WebAuthn authenticator (passkeys, security keys).
+/// The authenticator signs `authenticator_data || sha256(client_data_json)`,
+/// and the challenge inside `client_data_json` must be the base64url encoded hash of the signed item.
+#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct WebAuthnSignature<M: ManagedTypeApi> {
+    pub authenticator_data: ManagedBuffer<M>,
+    pub client_data_json: ManagedBuffer<M>,
+    /// DER encoded, as returned by the authenticator
+    pub signature: ManagedBuffer<M>,
+}
+
+impl<M: ManagedTypeApi + CryptoApi> WebAuthnSignature<M> {
+    pub fn decode_from_raw(raw_sig_bytes: &ManagedBuffer<M>) -> Self {
+        match Self::top_decode(raw_sig_bytes.clone()) {
+            Result::Ok(webauthn_sig) => webauthn_sig,
+            Result::Err(_) => {
+                M::error_api_impl().signal_error(b"Invalid WebAuthn signature encoding")
+            }
+        }
+    }
+
+    /// Checks the envelope against the expected challenge
+    /// and returns the message that was actually signed by the authenticator.
+    pub fn build_signed_message(&self, challenge: &ManagedBuffer<M>) -> ManagedBuffer<M> {
+        let mut challenge_bytes = [0u8; SHA256_RESULT_LEN];
+        if challenge.len() != SHA256_RESULT_LEN
+            || challenge.load_slice(0, &mut challenge_bytes).is_err()
+        {
+            M::error_api_impl().signal_error(b"Invalid WebAuthn challenge");
+        }
+
+        let mut auth_data_bytes = [0u8; MAX_AUTHENTICATOR_DATA_LEN];
+        let auth_data = load_bounded::<M>(
+            &self.authenticator_data,
+            &mut auth_data_bytes,
+            b"Authenticator data too long",
+        );
+        if let Result::Err(err_msg) = check_authenticator_data(auth_data) {
+            M::error_api_impl().signal_error(err_msg);
+        }
+
+        let mut client_data_bytes = [0u8; MAX_CLIENT_DATA_JSON_LEN];
+        let client_data = load_bounded::<M>(
+            &self.client_data_json,
+            &mut client_data_bytes,
+            b"Client data JSON too long",
+        );
+        if let Result::Err(err_msg) = check_client_data_json(client_data, &challenge_bytes) {
+            M::error_api_impl().signal_error(err_msg);
+        }
+
+        let client_data_hash = ManagedBuffer::<M>::new();
+        M::crypto_api_impl().sha256_managed(
+            client_data_hash.get_handle(),
+            self.client_data_json.get_handle(),
+        );
+
+        let mut signed_message = self.authenticator_data.clone();
+        signed_message.append(&client_data_hash);
+
+        signed_message
+    }
+}
+
+fn load_bounded<'a, M: ManagedTypeApi>(
+    buffer: &ManagedBuffer<M>,
+    dest: &'a mut [u8],
+    too_long_err_msg: &[u8],
+) -> &'a [u8] {
+    let len = buffer.len();
+    if len > dest.len() {
+        M::error_api_impl().signal_error(too_long_err_msg);
+    }
+
+    let dest_slice = &mut dest[..len];
+    if buffer.load_slice(0, dest_slice).is_err() {
+        M::error_api_impl().signal_error(too_long_err_msg);
+    }
+
+    dest_slice
+}
+
+pub fn check_authenticator_data(authenticator_data: &[u8]) -> Result<(), &'static [u8]> {
+    if authenticator_data.len() < AUTHENTICATOR_DATA_MIN_LEN {
+        return Result::Err(AUTH_DATA_TOO_SHORT_ERR_MSG);
+    }
+
+    let flags = authenticator_data[AUTHENTICATOR_DATA_FLAGS_INDEX];
+    if flags & USER_PRESENT_FLAG == 0 {
+        return Result::Err(USER_NOT_PRESENT_ERR_MSG);
+    }
+
+    Result::Ok(())
+}
+
+pub fn check_client_data_json(
+    client_data_json: &[u8],
+    challenge: &[u8; SHA256_RESULT_LEN],
+) -> Result<(), &'static [u8]> {
+    if find_subslice(client_data_json, WEBAUTHN_GET_TYPE).is_none() {
+        return Result::Err(INVALID_CLIENT_DATA_TYPE_ERR_MSG);
+    }
+
+    let challenge_start = match find_subslice(client_data_json, CHALLENGE_KEY) {
+        Some(key_pos) => key_pos + CHALLENGE_KEY.len(),
+        None => return Result::Err(CHALLENGE_MISSING_ERR_MSG),
+    };
+
+    // encoded challenge followed by the closing quote
+    let challenge_end = challenge_start + CHALLENGE_BASE64_LEN;
+    if client_data_json.len() <= challenge_end {
+        return Result::Err(CHALLENGE_MISMATCH_ERR_MSG);
+    }
+
+    let expected_challenge = base64url_encode_digest(challenge);
+    if client_data_json[challenge_start..challenge_end] != expected_challenge
+        || client_data_json[challenge_end] != b'"'
+    {
+        return Result::Err(CHALLENGE_MISMATCH_ERR_MSG);
+    }
+
+    Result::Ok(())
+}
+
+/// Unpadded base64url encoding, as used by WebAuthn for the challenge.
+pub fn base64url_encode_digest(digest: &[u8; SHA256_RESULT_LEN]) -> [u8; CHALLENGE_BASE64_LEN] {
+    let mut result = [0u8; CHALLENGE_BASE64_LEN];
+    let mut out_index = 0;
+    for chunk in digest.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = chunk.get(1).copied().unwrap_or_default() as u32;
+        let b2 = chunk.get(2).copied().unwrap_or_default() as u32;
+        let triple = (b0 << 16) | (b1 << 8) | b2;
+
+        // 3 input bytes produce 4 output chars, a trailing chunk of N bytes produces N + 1
+        let nr_chars = chunk.len() + 1;
+        for i in 0..nr_chars {
+            let sextet = (triple >> (18 - 6 * i)) & 0x3F;
+            result[out_index] = BASE64_URL_ALPHABET[sextet as usize];
+            out_index += 1;
+        }
+    }
+
+    result
+}
+
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DIGEST: [u8; SHA256_RESULT_LEN] = [0xFB; SHA256_RESULT_LEN];
+
+    fn client_data(challenge: &[u8]) -> ([u8; MAX_CLIENT_DATA_JSON_LEN], usize) {
+        let mut result = [0u8; MAX_CLIENT_DATA_JSON_LEN];
+        let parts: [&[u8]; 3] = [
+            br#"{"type":"webauthn.get","challenge":""#,
+            challenge,
+            br#"","origin":"https://wallet.multiversx.com"}"#,
+        ];
+
+        let mut len = 0;
+        for part in parts {
+            result[len..len + part.len()].copy_from_slice(part);
+            len += part.len();
+        }
+
+        (result, len)
+    }
+
+    #[test]
+    fn test_base64url_encode() {
+        let encoded = base64url_encode_digest(&DIGEST);
+        assert_eq!(&encoded[..4], b"-_v7");
+        assert_eq!(&encoded[40..], b"-_s");
+
+        let encoded = base64url_encode_digest(&[0u8; SHA256_RESULT_LEN]);
+        assert_eq!(&encoded[..], &[b'A'; CHALLENGE_BASE64_LEN][..]);
+    }
+
+    #[test]
+    fn test_client_data_json() {
+        let challenge = base64url_encode_digest(&DIGEST);
+        let (data, len) = client_data(&challenge);
+        assert!(check_client_data_json(&data[..len], &DIGEST).is_ok());
+
+        let mut tampered_challenge = challenge;
+        tampered_challenge[0] = b'A';
+        let (data, len) = client_data(&tampered_challenge);
+        assert_eq!(
+            check_client_data_json(&data[..len], &DIGEST),
+            Result::Err(CHALLENGE_MISMATCH_ERR_MSG)
+        );
+
+        let (data, len) = client_data(&challenge[..CHALLENGE_BASE64_LEN - 1]);
+        assert!(check_client_data_json(&data[..len], &DIGEST).is_err());
+
+        let (mut data, len) = client_data(&challenge);
+        data[18..21].copy_from_slice(b"new");
+        assert_eq!(
+            check_client_data_json(&data[..len], &DIGEST),
+            Result::Err(INVALID_CLIENT_DATA_TYPE_ERR_MSG)
+        );
+    }
+
+    #[test]
+    fn test_authenticator_data() {
+        let mut auth_data = [0u8; AUTHENTICATOR_DATA_MIN_LEN];
+        assert!(check_authenticator_data(&auth_data).is_err());
+        assert!(check_authenticator_data(&auth_data[..36]).is_err());
+
+        auth_data[AUTHENTICATOR_DATA_FLAGS_INDEX] = USER_PRESENT_FLAG;
+        assert!(check_authenticator_data(&auth_data).is_ok());
+    }
+}
@@ -1,7 +1,9 @@
 use multiversx_sc_modules::transfer_role_proxy::PaymentsVec;
 
 use crate::{
-    common_types::action::{ActionFullInfo, ActionId, GasLimit},
+    common_types::action::{
+        ActionFullInfo, ActionId, ActionKind, GasLimit, GroupId, Timestamp, Weight,
+    },
     common_types::user_role::UserRole,
 };
 
@@ -10,8 +12,22 @@ multiversx_sc::imports!();
 /// Contains all events that can be emitted by the contract.
 #[multiversx_sc::module]
 pub trait EventsModule {
+    /// `approving_module` is set when the action was executed without signatures,
+    /// because a module approved it.
     #[event("startPerformAction")]
-    fn start_perform_action_event(&self, data: &ActionFullInfo<Self::Api>);
+    fn start_perform_action_event(
+        &self,
+        #[indexed] approving_module: &Option<ManagedAddress>,
+        data: &ActionFullInfo<Self::Api>,
+    );
+
+    #[event("moduleApprovedAction")]
+    fn module_approved_action_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] module_address: &ManagedAddress,
+        #[indexed] proposer: &ManagedAddress,
+    );
 
     #[event("performChangeUser")]
     fn perform_change_user_event(
@@ -22,11 +38,113 @@ pub trait EventsModule {
         #[indexed] new_role: UserRole,
     );
 
+    /// `action_kind` is empty when the default quorum is changed.
+    /// When the override for an action type is removed, `new_quorum` is the default quorum.
     #[event("performChangeQuorum")]
     fn perform_change_quorum_event(
         &self,
         #[indexed] action_id: ActionId,
         #[indexed] new_quorum: usize,
+        #[indexed] action_kind: Option<ActionKind>,
+    );
+
+    /// `action_kind` is empty when the default delay is changed.
+    /// `delay` is empty when the override for the action type is removed.
+    #[event("performSetExecutionDelay")]
+    fn perform_set_execution_delay_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] action_kind: Option<ActionKind>,
+        #[indexed] delay: Option<Timestamp>,
+    );
+
+    #[event("performSetMaxBoardMembers")]
+    fn perform_set_max_board_members_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] max_board_members: usize,
+    );
+
+    #[event("performSetMaxModules")]
+    fn perform_set_max_modules_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] max_modules: usize,
+    );
+
+    #[event("performSetMaxAsyncCallRetries")]
+    fn perform_set_max_async_call_retries_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] max_retries: usize,
+    );
+
+    #[event("performSetBoardMemberWeight")]
+    fn perform_set_board_member_weight_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] board_member: &ManagedAddress,
+        #[indexed] old_weight: Weight,
+        #[indexed] new_weight: Weight,
+    );
+
+    #[event("performReplaceBoardMember")]
+    fn perform_replace_board_member_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] old_board_member: &ManagedAddress,
+        #[indexed] new_board_member: &ManagedAddress,
+        #[indexed] migrated_signatures: bool,
+    );
+
+    /// Summary of the board after a `ReconfigureBoard` action.
+    /// Every changed user also gets its own `performChangeUser` event.
+    #[event("performReconfigureBoard")]
+    fn perform_reconfigure_board_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] new_quorum: usize,
+        #[indexed] num_board_members: usize,
+        #[indexed] num_proposers: usize,
+    );
+
+    /// `group_id` is 0 if the action was not part of a batch.
+    #[event("actionVetoed")]
+    fn action_vetoed_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] group_id: GroupId,
+        #[indexed] guardian: &ManagedAddress,
+    );
+
+    #[event("pauseContract")]
+    fn pause_event(&self, #[indexed] caller: &ManagedAddress);
+
+    #[event("performUnpause")]
+    fn perform_unpause_event(&self, #[indexed] action_id: ActionId);
+
+    #[event("performSetRecoveryConfig")]
+    fn perform_set_recovery_config_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] challenge_period: Timestamp,
+        recovery_addresses: &ManagedVec<ManagedAddress>,
+    );
+
+    #[event("recoveryStarted")]
+    fn recovery_started_event(
+        &self,
+        #[indexed] initiator: &ManagedAddress,
+        #[indexed] new_quorum: usize,
+        new_board: &ManagedVec<ManagedAddress>,
+    );
+
+    #[event("recoveryFinished")]
+    fn recovery_finished_event(
+        &self,
+        #[indexed] initiator: &ManagedAddress,
+        #[indexed] new_quorum: usize,
+        new_board: &ManagedVec<ManagedAddress>,
     );
 
     #[event("performAddModuleEvent")]
@@ -43,6 +161,22 @@ pub trait EventsModule {
         #[indexed] sc_address: &ManagedAddress,
     );
 
+    #[event("performSetModuleNotifications")]
+    fn perform_set_module_notifications_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] sc_address: &ManagedAddress,
+        #[indexed] enabled: bool,
+    );
+
+    #[event("performSetModuleActionApproval")]
+    fn perform_set_module_action_approval_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] sc_address: &ManagedAddress,
+        #[indexed] enabled: bool,
+    );
+
     #[event("performAsyncCall")]
     fn perform_async_call_event(
         &self,
@@ -76,6 +210,28 @@ pub trait EventsModule {
         #[indexed] arguments: &MultiValueManagedVec<ManagedBuffer>,
     );
 
+    #[event("performPromiseCallEgld")]
+    fn perform_promise_call_egld_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] egld_value: &BigUint,
+        #[indexed] gas: GasLimit,
+        #[indexed] endpoint: &ManagedBuffer,
+        #[indexed] arguments: &MultiValueManagedVec<ManagedBuffer>,
+    );
+
+    #[event("performPromiseCallEsdt")]
+    fn perform_promise_call_esdt_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] to: &ManagedAddress,
+        #[indexed] tokens: &PaymentsVec<Self::Api>,
+        #[indexed] gas: GasLimit,
+        #[indexed] endpoint: &ManagedBuffer,
+        #[indexed] arguments: &MultiValueManagedVec<ManagedBuffer>,
+    );
+
     #[event("performDeployFromSource")]
     fn perform_deploy_from_source_event(
         &self,
@@ -87,6 +243,21 @@ pub trait EventsModule {
         #[indexed] arguments: &MultiValueManagedVec<ManagedBuffer>,
     );
 
+    #[event("contractDeployed")]
+    fn contract_deployed_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] new_address: &ManagedAddress,
+    );
+
+    #[event("performChangeOwner")]
+    fn perform_change_owner_event(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] target_address: &ManagedAddress,
+        #[indexed] new_owner: &ManagedAddress,
+    );
+
     #[event("performUpgradeFromSource")]
     fn perform_upgrade_from_source_event(
         &self,
@@ -1,5 +1,11 @@
 use crate::common_types::{
-    action::{Action, ActionFullInfo, ActionId, Nonce},
+    action::{
+        Action, ActionExecutionResult, ActionFullInfo, ActionId, ActionKind, AsyncCallRetryInfo,
+        GroupExecutionStatus, GroupId, ModuleExecutionInfo, Nonce, PromiseCallStatus, Timestamp,
+        Weight,
+    },
+    board::{BoardMemberOverview, MemberActivity},
+    signature::SignatureType,
     user_role::UserRole,
 };
 
@@ -22,7 +28,8 @@ pub trait ViewsModule:
     /// Serialized full action data:
     /// - the action id
     /// - the serialized action data
-    /// - (number of signers followed by) list of signer addresses.
+    /// - (number of signers followed by) list of signer addresses
+    /// - the optional deadline.
     #[label("multisig-external-view")]
     #[allow_multiple_var_args]
     #[view(getPendingActionFullInfo)]
@@ -56,6 +63,7 @@ pub trait ViewsModule:
                     action_data,
                     signers: self.get_action_signers(action_id),
                     group_id: self.group_for_action(action_id).get(),
+                    deadline: self.action_deadline(action_id).get(),
                 });
             }
         }
@@ -81,6 +89,14 @@ pub trait ViewsModule:
         self.get_action_valid_signer_count(action_id)
     }
 
+    /// Sum of the weights of the signers that are still board members.
+    /// The action can be performed once this reaches the quorum.
+    #[label("multisig-external-view")]
+    #[view(getActionValidSignerWeight)]
+    fn get_action_valid_signer_weight_view(&self, action_id: ActionId) -> Weight {
+        self.get_action_valid_signer_weight(action_id)
+    }
+
     /// Gets addresses of all users who signed an action.
     /// Does not check if those users are still board members or not,
     /// so the result may contain invalid signers.
@@ -93,7 +109,9 @@ pub trait ViewsModule:
     /// Indicates user rights.
     /// `0` = no rights,
     /// `1` = can propose, but not sign,
-    /// `2` = can propose and sign.
+    /// `2` = can propose and sign,
+    /// `3` = can only veto pending actions,
+    /// `4` = can only perform actions that reached quorum.
     #[label("multisig-external-view")]
     #[view(userRole)]
     fn user_role(&self, user: ManagedAddress) -> UserRole {
@@ -109,7 +127,110 @@ pub trait ViewsModule:
     #[label("multisig-external-view")]
     #[view(getAllBoardMembers)]
     fn get_all_board_members(&self) -> MultiValueEncoded<ManagedAddress> {
-        self.get_all_users_with_role(UserRole::BoardMember)
+        self.require_storage_migrated();
+
+        let mut result = MultiValueEncoded::new();
+        for user_id in self.board_member_ids().iter() {
+            if let Some(address) = self.user_ids().get_address(user_id) {
+                result.push(address);
+            }
+        }
+
+        result
+    }
+
+    /// Lists all users that can sign actions, together with their voting weight.
+    #[label("multisig-external-view")]
+    #[view(getAllBoardMembersWithWeight)]
+    fn get_all_board_members_with_weight(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<ManagedAddress, Weight>> {
+        self.require_storage_migrated();
+
+        let mut result = MultiValueEncoded::new();
+        for user_id in self.board_member_ids().iter() {
+            if let Some(address) = self.user_ids().get_address(user_id) {
+                result.push((address, self.get_user_weight(user_id)).into());
+            }
+        }
+
+        result
+    }
+
+    /// Lists all board members with their voting weight and last activity.
+    #[label("multisig-external-view")]
+    #[view(getBoardOverview)]
+    fn get_board_overview(&self) -> MultiValueEncoded<BoardMemberOverview<Self::Api>> {
+        self.require_storage_migrated();
+
+        let mut result = MultiValueEncoded::new();
+        for user_id in self.board_member_ids().iter() {
+            if let Some(address) = self.user_ids().get_address(user_id) {
+                result.push(BoardMemberOverview {
+                    address,
+                    weight: self.get_user_weight(user_id),
+                    last_activity: self.get_member_activity_by_id(user_id),
+                });
+            }
+        }
+
+        result
+    }
+
+    /// Block timestamp and round of the last propose, sign, unsign or perform of the user.
+    /// Empty if the user never did any of them.
+    #[label("multisig-external-view")]
+    #[view(getMemberActivity)]
+    fn get_member_activity(&self, user: ManagedAddress) -> Option<MemberActivity> {
+        let user_id = self.user_ids().get_id(&user);
+        if user_id == 0 {
+            return None;
+        }
+
+        self.get_member_activity_by_id(user_id)
+    }
+
+    /// Lists the board members that did not propose, sign, unsign or perform anything
+    /// since the given block timestamp.
+    #[label("multisig-external-view")]
+    #[view(getInactiveMembers)]
+    fn get_inactive_members(&self, since: Timestamp) -> MultiValueEncoded<ManagedAddress> {
+        self.require_storage_migrated();
+
+        let mut result = MultiValueEncoded::new();
+        for user_id in self.board_member_ids().iter() {
+            let is_active = self
+                .get_member_activity_by_id(user_id)
+                .is_some_and(|activity| activity.timestamp >= since);
+            if is_active {
+                continue;
+            }
+
+            if let Some(address) = self.user_ids().get_address(user_id) {
+                result.push(address);
+            }
+        }
+
+        result
+    }
+
+    /// Voting weight of a board member. `0` if the user is not a board member.
+    #[label("multisig-external-view")]
+    #[view(getBoardMemberWeight)]
+    fn get_board_member_weight(&self, user: ManagedAddress) -> Weight {
+        let (user_id, user_role) = self.get_id_and_role(&user);
+        if user_role != UserRole::BoardMember {
+            return 0;
+        }
+
+        self.get_user_weight(user_id)
+    }
+
+    /// Sum of the weights of all the board members. The quorum cannot exceed it.
+    #[label("multisig-external-view")]
+    #[view(getTotalBoardWeight)]
+    fn get_total_board_weight_view(&self) -> Weight {
+        self.total_board_weight().get()
     }
 
     /// Lists all proposers that are not board members.
@@ -119,6 +240,18 @@ pub trait ViewsModule:
         self.get_all_users_with_role(UserRole::Proposer)
     }
 
+    #[label("multisig-external-view")]
+    #[view(getAllGuardians)]
+    fn get_all_guardians(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.get_all_users_with_role(UserRole::Guardian)
+    }
+
+    #[label("multisig-external-view")]
+    #[view(getAllExecutors)]
+    fn get_all_executors(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.get_all_users_with_role(UserRole::Executor)
+    }
+
     /// Serialized action data of an action with index.
     #[label("multisig-external-view")]
     #[view(getActionData)]
@@ -145,12 +278,132 @@ pub trait ViewsModule:
         self.action_mapper().len()
     }
 
+    /// Block timestamp starting from which the action can be performed, given the execution delay.
+    /// Empty if the action did not reach quorum yet.
+    #[label("multisig-external-view")]
+    #[view(getEarliestExecutionTime)]
+    fn get_earliest_execution_time_view(&self, action_id: ActionId) -> Option<Timestamp> {
+        self.require_action_exists(action_id);
+
+        self.get_earliest_execution_time(action_id)
+    }
+
+    /// Pending actions of the batch, in the order they were proposed and will be performed.
+    #[label("multisig-external-view")]
+    #[view(getActionGroupOrdered)]
+    fn get_action_group_ordered(&self, group_id: GroupId) -> MultiValueEncoded<ActionId> {
+        self.get_group_actions_in_order(group_id).into()
+    }
+
+    /// Batches ending with an async call or an upgrade stay pending until the callback is received,
+    /// and batches with promise calls until all of their callbacks are received.
+    #[label("multisig-external-view")]
+    #[view(getGroupExecutionStatus)]
+    fn get_group_execution_status(&self, group_id: GroupId) -> GroupExecutionStatus {
+        self.group_execution_status(group_id).get()
+    }
+
+    /// True once all the actions of the batch were performed, including the final async call or upgrade,
+    /// or all the promise calls.
+    #[label("multisig-external-view")]
+    #[view(isBatchSuccessful)]
+    fn is_batch_successful(&self, group_id: GroupId) -> bool {
+        self.group_execution_status(group_id).get() == GroupExecutionStatus::Succeeded
+    }
+
+    /// Result of a call sent through the promises API, `Pending` until its callback is received.
+    /// The status is kept after the action is performed and cleared from storage.
+    #[label("multisig-external-view")]
+    #[view(getPromiseCallStatus)]
+    fn get_promise_call_status(&self, action_id: ActionId) -> PromiseCallStatus {
+        self.promise_call_status(action_id).get()
+    }
+
+    /// Result of a performed async call, upgrade or promise call.
+    /// Empty for other actions, or if the callback was not received yet.
+    #[label("multisig-external-view")]
+    #[view(getActionExecutionResult)]
+    fn get_action_execution_result(
+        &self,
+        action_id: ActionId,
+    ) -> Option<ActionExecutionResult<Self::Api>> {
+        let mapper = self.action_execution_result(action_id);
+        if mapper.is_empty() {
+            return None;
+        }
+
+        Some(mapper.get())
+    }
+
+    /// Attempts made so far for an async call that can be retried.
+    /// Empty if retries are disabled, or once the action was cleared.
+    #[label("multisig-external-view")]
+    #[view(getAsyncCallRetryInfo)]
+    fn get_async_call_retry_info(&self, action_id: ActionId) -> Option<AsyncCallRetryInfo> {
+        let mapper = self.async_call_retry(action_id);
+        if mapper.is_empty() {
+            return None;
+        }
+
+        Some(mapper.get())
+    }
+
+    /// Block timestamp starting from which all the actions in the batch can be performed.
+    /// Empty if the batch did not reach quorum yet.
+    #[label("multisig-external-view")]
+    #[view(getGroupEarliestExecutionTime)]
+    fn get_group_earliest_execution_time(&self, group_id: GroupId) -> Option<Timestamp> {
+        let mut result = None;
+        for action_id in self.action_groups(group_id).iter() {
+            let earliest_execution_time = self.get_earliest_execution_time(action_id)?;
+            if result < Some(earliest_execution_time) {
+                result = Some(earliest_execution_time);
+            }
+        }
+
+        result
+    }
+
+    /// Actions executed without signatures because a module approved them,
+    /// from oldest to newest. Only the most recent executions are kept.
+    #[label("multisig-external-view")]
+    #[view(getRecentModuleExecutions)]
+    fn get_recent_module_executions(&self) -> MultiValueEncoded<ModuleExecutionInfo<Self::Api>> {
+        self.recent_module_executions().iter().collect()
+    }
+
+    /// The quorum that new actions of the given type will require.
+    /// Falls back to `getQuorum` if no override was set for the action type.
+    #[view(getActionKindQuorum)]
+    fn get_action_kind_quorum(&self, action_kind: ActionKind) -> usize {
+        self.get_quorum_for_action_kind(action_kind)
+    }
+
     #[view(getUserNonce)]
     fn get_user_nonce(&self, user_address: ManagedAddress) -> Nonce {
         let user_id = self.user_ids().get_id_non_zero(&user_address);
         self.user_nonce(user_id).get()
     }
 
+    #[view(getSigningKey)]
+    fn get_signing_key_view(
+        &self,
+        user_address: ManagedAddress,
+        signature_type: SignatureType,
+    ) -> ManagedBuffer {
+        let user_id = self.user_ids().get_id_non_zero(&user_address);
+        self.user_signing_key(user_id, signature_type).get()
+    }
+
+    fn get_member_activity_by_id(&self, user_id: AddressId) -> Option<MemberActivity> {
+        let mapper = self.member_activity(user_id);
+        if mapper.is_empty() {
+            return None;
+        }
+
+        Some(mapper.get())
+    }
+
     fn get_all_users_with_role(&self, role: UserRole) -> MultiValueEncoded<ManagedAddress> {
         let mut result = MultiValueEncoded::new();
         let num_users = self.user_ids().get_last_id();
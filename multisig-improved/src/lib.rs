@@ -1,6 +1,6 @@
 #![no_std]
 
-use action_types::execute_action::{BOARD_SIZE_TOO_BIG_ERR_MSG, MAX_BOARD_MEMBERS};
+use action_types::execute_action::{BOARD_SIZE_TOO_BIG_ERR_MSG, QUORUM_TOO_BIG_ERR_MSG};
 use common_types::user_role::UserRole;
 
 pub mod action_types;
@@ -24,6 +24,9 @@ pub trait Multisig:
     + ms_endpoints::propose::ProposeEndpointsModule
     + ms_endpoints::perform::PerformEndpointsModule
     + ms_endpoints::discard::DiscardEndpointsModule
+    + ms_endpoints::migration::MigrationEndpointsModule
+    + ms_endpoints::pause::PauseEndpointsModule
+    + ms_endpoints::recovery::RecoveryEndpointsModule
     + ms_endpoints::sign::SignEndpointsModule
     + ms_endpoints::callbacks::CallbacksModule
     + action_types::external_module::ExternalModuleModule
@@ -36,8 +39,17 @@ pub trait Multisig:
     + external::views::ViewsModule
     + multiversx_sc_modules::dns::DnsModule
 {
+    /// Empty limits default to 30 board members and 5 modules.
     #[init]
-    fn init(&self, quorum: usize, board: MultiValueEncoded<ManagedAddress>) {
+    fn init(
+        &self,
+        quorum: usize,
+        opt_max_board_members: Option<usize>,
+        opt_max_modules: Option<usize>,
+        board: MultiValueEncoded<ManagedAddress>,
+    ) {
+        self.init_limits(opt_max_board_members, opt_max_modules);
+
         let board_vec = board.to_vec();
         let new_num_board_members = self.add_initial_board_members(board_vec);
         require!(
@@ -45,15 +57,37 @@ pub trait Multisig:
             "board cannot be empty on init, no-one would be able to propose"
         );
 
-        require!(
-            quorum <= new_num_board_members,
-            "quorum cannot exceed board size"
-        );
+        require!(quorum <= new_num_board_members, QUORUM_TOO_BIG_ERR_MSG);
         self.quorum().set(quorum);
+        self.init_quorum_tracking();
     }
 
+    /// Limits that are not provided keep their current value.
+    #[allow_multiple_var_args]
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(
+        &self,
+        opt_max_board_members: OptionalValue<usize>,
+        opt_max_modules: OptionalValue<usize>,
+    ) {
+        self.init_limits(
+            opt_max_board_members.into_option(),
+            opt_max_modules.into_option(),
+        );
+        self.init_quorum_tracking();
+
+        // all the members of older versions have the default weight
+        if self.total_board_weight().is_empty() {
+            self.total_board_weight()
+                .set(self.num_board_members().get());
+        }
+
+        // older versions indexed neither the board nor the signatures of each user,
+        // which may take more than one transaction, see `migrateStorage`
+        if self.board_member_ids().is_empty() && self.num_board_members().get() > 0 {
+            self.storage_migration_pending().set(true);
+        }
+    }
 
     /// Allows the contract to receive funds even if it is marked as unpayable in the protocol.
     #[payable("*")]
@@ -63,7 +97,7 @@ pub trait Multisig:
     fn add_initial_board_members(&self, new_board_members: ManagedVec<ManagedAddress>) -> usize {
         let new_board_members_len = new_board_members.len();
         require!(
-            new_board_members_len <= MAX_BOARD_MEMBERS,
+            new_board_members_len <= self.max_board_members().get(),
             BOARD_SIZE_TOO_BIG_ERR_MSG
         );
 
@@ -71,9 +105,11 @@ pub trait Multisig:
         for new_member in &new_board_members {
             let user_id = mapper.insert_new(&new_member);
             self.user_id_to_role(user_id).set(UserRole::BoardMember);
+            let _ = self.board_member_ids().insert(user_id);
         }
 
         self.num_board_members().set(new_board_members_len);
+        self.total_board_weight().set(new_board_members_len);
 
         new_board_members_len
     }
@@ -1,26 +1,156 @@
+use crate::common_types::action::{
+    ActionExecutionResult, ActionId, GroupExecutionStatus, GroupId, PromiseCallStatus,
+};
+
 multiversx_sc::imports!();
 
+/// Longer error messages are cut, the full message is still in the `asyncCallError` event.
+pub const MAX_STORED_ERR_MESSAGE_LEN: usize = 64;
+
 #[multiversx_sc::module]
-pub trait CallbacksModule {
-    /// Callback only performs logging.
+pub trait CallbacksModule:
+    crate::state::StateModule + crate::common_functions::CommonFunctionsModule
+{
+    /// Records the result of async calls and upgrades.
+    /// If it was the last step of a batch, it also completes the batch.
+    /// Failed async calls that still have retries left are kept, together with their signatures.
     #[callback]
     fn perform_async_call_callback(
         &self,
+        action_id: ActionId,
+        group_id: GroupId,
         #[call_result] call_result: ManagedAsyncCallResult<MultiValueEncoded<ManagedBuffer>>,
     ) {
-        match call_result {
+        let success = self.record_execution_result(action_id, call_result);
+        self.complete_async_call_attempt(action_id, success);
+
+        let group_status = if success {
+            GroupExecutionStatus::Succeeded
+        } else {
+            GroupExecutionStatus::Failed
+        };
+
+        if group_id != 0 {
+            self.group_execution_status(group_id).set(group_status);
+        }
+    }
+
+    /// Records the result of a call sent through the promises API.
+    /// Unlike the legacy async call, several of them can be sent in the same transaction.
+    /// A batch completes once the last of its promise calls returns, and fails if any of them failed.
+    #[promises_callback]
+    fn perform_promise_call_callback(
+        &self,
+        action_id: ActionId,
+        group_id: GroupId,
+        #[call_result] call_result: ManagedAsyncCallResult<MultiValueEncoded<ManagedBuffer>>,
+    ) {
+        let success = self.record_execution_result(action_id, call_result);
+        let call_status = if success {
+            PromiseCallStatus::Succeeded
+        } else {
+            PromiseCallStatus::Failed
+        };
+        self.promise_call_status(action_id).set(call_status);
+
+        if group_id == 0 {
+            return;
+        }
+
+        let pending_mapper = self.group_pending_promise_calls(group_id);
+        let pending_calls = pending_mapper.get().saturating_sub(1);
+        pending_mapper.set(pending_calls);
+
+        let status_mapper = self.group_execution_status(group_id);
+        if !success {
+            status_mapper.set(GroupExecutionStatus::Failed);
+        } else if pending_calls == 0
+            && status_mapper.get() == GroupExecutionStatus::PromiseCallsPending
+        {
+            status_mapper.set(GroupExecutionStatus::Succeeded);
+        }
+    }
+
+    /// The action is cleared once it succeeds or runs out of retries.
+    /// Otherwise any executor can perform it again, as long as it did not expire.
+    fn complete_async_call_attempt(&self, action_id: ActionId, success: bool) {
+        let retry_mapper = self.async_call_retry(action_id);
+        if retry_mapper.is_empty() {
+            return;
+        }
+
+        let mut retry_info = retry_mapper.get();
+        let max_attempts = self.max_async_call_retries().get() + 1;
+        if success || retry_info.attempts >= max_attempts {
+            self.clear_action(action_id);
+            return;
+        }
+
+        retry_info.in_progress = false;
+        retry_mapper.set(retry_info);
+    }
+
+    /// Only the hash of the returned data and the beginning of the error message are kept, to limit the storage used.
+    /// The hash is the SHA-256 of the returned values encoded as a list of buffers,
+    /// i.e. each value as its length (4 bytes, big endian) followed by its bytes.
+    /// Returns true if the call succeeded.
+    fn record_execution_result(
+        &self,
+        action_id: ActionId,
+        call_result: ManagedAsyncCallResult<MultiValueEncoded<ManagedBuffer>>,
+    ) -> bool {
+        let result = match call_result {
             ManagedAsyncCallResult::Ok(results) => {
-                self.async_call_success(results);
+                self.async_call_success(action_id, &results);
+
+                let mut encoded_results = ManagedBuffer::new();
+                let _ = results.to_vec().top_encode(&mut encoded_results);
+
+                ActionExecutionResult {
+                    success: true,
+                    err_code: 0,
+                    err_message: ManagedBuffer::new(),
+                    return_data_hash: self.crypto().sha256(encoded_results),
+                }
             }
             ManagedAsyncCallResult::Err(err) => {
-                self.async_call_error(err.err_code, err.err_msg);
+                self.async_call_error(action_id, err.err_code, &err.err_msg);
+
+                let err_message = if err.err_msg.len() > MAX_STORED_ERR_MESSAGE_LEN {
+                    err.err_msg
+                        .copy_slice(0, MAX_STORED_ERR_MESSAGE_LEN)
+                        .unwrap_or_default()
+                } else {
+                    err.err_msg
+                };
+
+                ActionExecutionResult {
+                    success: false,
+                    err_code: err.err_code,
+                    err_message,
+                    return_data_hash: ManagedByteArray::default(),
+                }
             }
-        }
+        };
+
+        let success = result.success;
+        self.action_execution_result(action_id).set(result);
+
+        success
     }
 
     #[event("asyncCallSuccess")]
-    fn async_call_success(&self, #[indexed] results: MultiValueEncoded<ManagedBuffer>);
+    fn async_call_success(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] results: &MultiValueEncoded<ManagedBuffer>,
+    );
 
     #[event("asyncCallError")]
-    fn async_call_error(&self, #[indexed] err_code: u32, #[indexed] err_message: ManagedBuffer);
+    fn async_call_error(
+        &self,
+        #[indexed] action_id: ActionId,
+        #[indexed] err_code: u32,
+        #[indexed] err_message: &ManagedBuffer,
+    );
 }
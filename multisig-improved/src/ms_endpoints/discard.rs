@@ -37,4 +37,25 @@ pub trait DiscardEndpointsModule:
             self.discard_action(action_id);
         }
     }
+
+    /// Guardians can cancel any pending action before it is performed, regardless of its signatures.
+    /// Vetoing an action that is part of a batch aborts the whole batch.
+    #[endpoint(vetoAction)]
+    fn veto_action_endpoint(&self, action_id: ActionId) {
+        let (_, caller_role) = self.get_caller_id_and_role();
+        caller_role.require_can_veto::<Self::Api>();
+
+        let caller = self.blockchain().get_caller();
+        self.veto_action(action_id, &caller);
+    }
+
+    /// Clears storage pertaining to actions whose deadline has passed.
+    /// Can be called by anyone, as these actions can no longer be signed or performed.
+    /// Discarding an action that is part of a batch aborts the whole batch.
+    #[endpoint(discardExpiredActions)]
+    fn discard_expired_actions(&self, action_ids: MultiValueEncoded<ActionId>) {
+        for action_id in action_ids {
+            self.discard_expired_action(action_id);
+        }
+    }
 }
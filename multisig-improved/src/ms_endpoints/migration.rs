@@ -0,0 +1,70 @@
+use crate::common_types::user_role::UserRole;
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait MigrationEndpointsModule:
+    crate::common_functions::CommonFunctionsModule + crate::state::StateModule
+{
+    /// Indexes the storage kept by older versions, started by the upgrade.
+    /// Goes through at most `max_steps` users and actions per call, the users first,
+    /// so it is never bound by the gas limit of a single transaction.
+    /// Anyone can call it until it returns `true`.
+    #[endpoint(migrateStorage)]
+    fn migrate_storage(&self, max_steps: usize) -> bool {
+        require!(
+            self.storage_migration_pending().get(),
+            "no storage migration pending"
+        );
+        require!(max_steps > 0, "invalid number of steps");
+
+        let mut steps_left = max_steps;
+        if !self.migrate_board_member_ids(&mut steps_left)
+            || !self.migrate_user_signed_actions(&mut steps_left)
+        {
+            return false;
+        }
+
+        self.migrated_users_count().clear();
+        self.migrated_actions_count().clear();
+        self.storage_migration_pending().clear();
+
+        true
+    }
+
+    /// Users added after the upgrade are already indexed, going through them again has no effect.
+    fn migrate_board_member_ids(&self, steps_left: &mut usize) -> bool {
+        let last_user_id = self.user_ids().get_last_id();
+        let mut user_id = self.migrated_users_count().get();
+        while user_id < last_user_id && *steps_left > 0 {
+            user_id += 1;
+            *steps_left -= 1;
+
+            if self.user_id_to_role(user_id).get() == UserRole::BoardMember {
+                let _ = self.board_member_ids().insert(user_id);
+            }
+        }
+
+        self.migrated_users_count().set(user_id);
+
+        user_id == last_user_id
+    }
+
+    /// Same for the actions proposed after the upgrade.
+    fn migrate_user_signed_actions(&self, steps_left: &mut usize) -> bool {
+        let last_action_id = self.action_mapper().len();
+        let mut action_id = self.migrated_actions_count().get();
+        while action_id < last_action_id && *steps_left > 0 {
+            action_id += 1;
+            *steps_left -= 1;
+
+            for signer_id in self.action_signer_ids(action_id).iter() {
+                let _ = self.user_signed_actions(signer_id).insert(action_id);
+            }
+        }
+
+        self.migrated_actions_count().set(action_id);
+
+        action_id == last_action_id
+    }
+}
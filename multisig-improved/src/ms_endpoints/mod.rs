@@ -1,5 +1,8 @@
 pub mod callbacks;
 pub mod discard;
+pub mod migration;
+pub mod pause;
 pub mod perform;
 pub mod propose;
+pub mod recovery;
 pub mod sign;
@@ -0,0 +1,23 @@
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait PauseEndpointsModule:
+    crate::common_functions::CommonFunctionsModule
+    + crate::state::StateModule
+    + crate::external::events::EventsModule
+{
+    /// Emergency stop, e.g. when a board member key leaks.
+    /// While paused, actions can be neither proposed nor performed,
+    /// except for rotating board members and unpausing, which requires the usual quorum.
+    #[endpoint]
+    fn pause(&self) {
+        let (_, caller_role) = self.get_caller_id_and_role();
+        caller_role.require_can_pause::<Self::Api>();
+        require!(!self.paused().get(), "already paused");
+
+        self.paused().set(true);
+
+        let caller = self.blockchain().get_caller();
+        self.pause_event(&caller);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::common_types::action::{ActionId, ActionStatus, GroupId};
+use crate::common_types::action::{ActionId, GroupId};
 
 multiversx_sc::imports!();
 
@@ -13,10 +13,12 @@ pub trait PerformEndpointsModule:
     + super::callbacks::CallbacksModule
 {
     /// Proposers and board members use this to launch signed actions.
+    /// If an execution delay is configured, it has to pass after the action reached quorum.
     #[endpoint(performAction)]
     fn perform_action_endpoint(&self, action_id: ActionId) -> OptionalValue<ManagedAddress> {
-        let (_, caller_role) = self.get_caller_id_and_role();
+        let (caller_id, caller_role) = self.get_caller_id_and_role();
         caller_role.require_can_perform_action::<Self::Api>();
+        self.record_member_activity(caller_id);
 
         require!(
             self.quorum_reached(action_id),
@@ -26,36 +28,19 @@ pub trait PerformEndpointsModule:
         let group_id = self.group_for_action(action_id).get();
         require!(group_id == 0, "May not execute this action by itself");
 
+        self.require_execution_delay_passed(action_id);
+
         self.perform_action_by_id(action_id)
     }
 
-    /// Perform all the actions in the given batch
+    /// Perform all the actions in the given batch, in the order they were proposed.
+    /// Fails without executing anything if any of them cannot be performed yet.
     #[endpoint(performBatch)]
     fn perform_batch(&self, group_id: GroupId) {
-        let (_, caller_role) = self.get_caller_id_and_role();
+        let (caller_id, caller_role) = self.get_caller_id_and_role();
         caller_role.require_can_perform_action::<Self::Api>();
+        self.record_member_activity(caller_id);
 
-        let group_status = self.action_group_status(group_id).get();
-        require!(
-            group_status == ActionStatus::Available,
-            "cannot perform actions of an aborted batch"
-        );
-
-        let mapper = self.action_groups(group_id);
-        require!(!mapper.is_empty(), "Invalid group ID");
-
-        let mut action_ids = ManagedVec::<Self::Api, _>::new();
-        for action_id in mapper.iter() {
-            action_ids.push(action_id);
-        }
-
-        for action_id in &action_ids {
-            require!(
-                self.quorum_reached(action_id),
-                "quorum has not been reached"
-            );
-
-            let _ = self.perform_action_by_id(action_id);
-        }
+        self.perform_batch_in_order(group_id);
     }
 }
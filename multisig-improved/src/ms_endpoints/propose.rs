@@ -1,9 +1,10 @@
 use multiversx_sc_modules::transfer_role_proxy::PaymentsVec;
 
+use crate::action_types::external_module::{CanExecuteArgs, ModuleId};
 use crate::common_types::{
     action::{
-        Action, ActionId, ActionStatus, CallActionData, DeployArgs, EsdtTransferExecuteData,
-        GasLimit, GroupId,
+        Action, ActionId, ActionKind, ActionStatus, CallActionData, Deadline, DeployArgs,
+        EsdtTransferExecuteData, GasLimit, GroupId, Timestamp, Weight,
     },
     signature::SignatureArg,
 };
@@ -28,9 +29,14 @@ pub trait ProposeEndpointsModule:
     fn propose_add_board_member(
         &self,
         board_member_address: ManagedAddress,
+        opt_deadline: Option<Deadline>,
         opt_signature: OptionalValue<SignatureArg<Self::Api>>,
     ) -> ActionId {
-        self.propose_action(&Action::AddBoardMember(board_member_address), opt_signature)
+        self.propose_action(
+            &Action::AddBoardMember(board_member_address),
+            opt_deadline,
+            opt_signature,
+        )
     }
 
     /// Initiates proposer addition process..
@@ -39,28 +45,169 @@ pub trait ProposeEndpointsModule:
     fn propose_add_proposer(
         &self,
         proposer_address: ManagedAddress,
+        opt_deadline: Option<Deadline>,
+        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
+    ) -> ActionId {
+        self.propose_action(
+            &Action::AddProposer(proposer_address),
+            opt_deadline,
+            opt_signature,
+        )
+    }
+
+    /// Guardians can neither propose nor sign, but can veto pending actions.
+    /// Can also be used to convert a board member or proposer to guardian.
+    #[endpoint(proposeAddGuardian)]
+    fn propose_add_guardian(
+        &self,
+        guardian_address: ManagedAddress,
+        opt_deadline: Option<Deadline>,
         opt_signature: OptionalValue<SignatureArg<Self::Api>>,
     ) -> ActionId {
-        self.propose_action(&Action::AddProposer(proposer_address), opt_signature)
+        self.propose_action(
+            &Action::AddGuardian(guardian_address),
+            opt_deadline,
+            opt_signature,
+        )
+    }
+
+    /// Executors can neither propose nor sign, only perform actions that reached quorum.
+    /// Can also be used to convert any other user to executor.
+    #[endpoint(proposeAddExecutor)]
+    fn propose_add_executor(
+        &self,
+        executor_address: ManagedAddress,
+        opt_deadline: Option<Deadline>,
+        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
+    ) -> ActionId {
+        self.propose_action(
+            &Action::AddExecutor(executor_address),
+            opt_deadline,
+            opt_signature,
+        )
+    }
+
+    /// Rotates the key of a board member in a single action, keeping its voting weight.
+    /// The new address must not have any role yet.
+    /// If `migrate_signatures` is set, the old address' signatures on pending actions are moved to the new address.
+    #[endpoint(proposeReplaceBoardMember)]
+    fn propose_replace_board_member(
+        &self,
+        old_board_member: ManagedAddress,
+        new_board_member: ManagedAddress,
+        migrate_signatures: bool,
+        opt_deadline: Option<Deadline>,
+        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
+    ) -> ActionId {
+        self.propose_action(
+            &Action::ReplaceBoardMember {
+                old_board_member,
+                new_board_member,
+                migrate_signatures,
+            },
+            opt_deadline,
+            opt_signature,
+        )
+    }
+
+    /// Changes several users and the quorum at once. The result is validated only at the end,
+    /// so board size and quorum combinations that can't be reached one step at a time are allowed.
+    #[endpoint(proposeReconfigureBoard)]
+    fn propose_reconfigure_board(
+        &self,
+        add: ManagedVec<ManagedAddress>,
+        remove: ManagedVec<ManagedAddress>,
+        proposers_add: ManagedVec<ManagedAddress>,
+        proposers_remove: ManagedVec<ManagedAddress>,
+        new_quorum: usize,
+        opt_deadline: Option<Deadline>,
+        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
+    ) -> ActionId {
+        self.propose_action(
+            &Action::ReconfigureBoard {
+                add,
+                remove,
+                proposers_add,
+                proposers_remove,
+                new_quorum,
+            },
+            opt_deadline,
+            opt_signature,
+        )
     }
 
-    /// Removes user regardless of whether it is a board member or proposer.
+    /// Removes user regardless of its role.
     #[endpoint(proposeRemoveUser)]
     fn propose_remove_user(
         &self,
         user_address: ManagedAddress,
+        opt_deadline: Option<Deadline>,
         opt_signature: OptionalValue<SignatureArg<Self::Api>>,
     ) -> ActionId {
-        self.propose_action(&Action::RemoveUser(user_address), opt_signature)
+        self.propose_action(
+            &Action::RemoveUser(user_address),
+            opt_deadline,
+            opt_signature,
+        )
+    }
+
+    /// Unlike `proposeRemoveUser`, fails when performed if the user is not a proposer.
+    #[endpoint(proposeRemoveProposer)]
+    fn propose_remove_proposer(
+        &self,
+        proposer_address: ManagedAddress,
+        opt_deadline: Option<Deadline>,
+        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
+    ) -> ActionId {
+        self.propose_action(
+            &Action::RemoveProposer(proposer_address),
+            opt_deadline,
+            opt_signature,
+        )
+    }
+
+    /// Fails when performed if the user is not a board member, or if the quorum can no longer be reached.
+    #[endpoint(proposeDemoteToProposer)]
+    fn propose_demote_to_proposer(
+        &self,
+        board_member_address: ManagedAddress,
+        opt_deadline: Option<Deadline>,
+        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
+    ) -> ActionId {
+        self.propose_action(
+            &Action::DemoteToProposer(board_member_address),
+            opt_deadline,
+            opt_signature,
+        )
+    }
+
+    /// Fails when performed if the user is not a proposer.
+    #[endpoint(proposePromoteToBoardMember)]
+    fn propose_promote_to_board_member(
+        &self,
+        proposer_address: ManagedAddress,
+        opt_deadline: Option<Deadline>,
+        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
+    ) -> ActionId {
+        self.propose_action(
+            &Action::PromoteToBoardMember(proposer_address),
+            opt_deadline,
+            opt_signature,
+        )
     }
 
     #[endpoint(proposeChangeQuorum)]
     fn propose_change_quorum(
         &self,
         new_quorum: usize,
+        opt_deadline: Option<Deadline>,
         opt_signature: OptionalValue<SignatureArg<Self::Api>>,
     ) -> ActionId {
-        self.propose_action(&Action::ChangeQuorum(new_quorum), opt_signature)
+        self.propose_action(
+            &Action::ChangeQuorum(new_quorum),
+            opt_deadline,
+            opt_signature,
+        )
     }
 
     /// Propose a transaction in which the contract will perform a transfer-execute call.
@@ -74,15 +221,15 @@ pub trait ProposeEndpointsModule:
         to: ManagedAddress,
         egld_amount: BigUint,
         opt_gas_limit: Option<GasLimit>,
+        opt_deadline: Option<Deadline>,
+        opt_signature: Option<SignatureArg<Self::Api>>,
         function_call: FunctionCall,
-        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
     ) -> OptionalValue<ActionId> {
         require!(
             egld_amount > 0 || !function_call.is_empty(),
             "proposed action has no effect"
         );
 
-        let proposer = self.get_proposer_no_sig_check(&opt_signature);
         let call_data = CallActionData {
             to,
             egld_amount,
@@ -90,16 +237,11 @@ pub trait ProposeEndpointsModule:
             endpoint_name: function_call.function_name,
             arguments: function_call.arg_buffer.into_vec_of_buffers(),
         };
-        let action = Action::SendTransferExecuteEgld(call_data.clone());
-        let action_id = self.add_action(&action);
-
-        if self.try_perform_egld_action_directly(&proposer, action_id, &call_data) {
-            return OptionalValue::None;
-        }
-
-        self.check_proposer_role_and_sign(action_id, &action, opt_signature);
-
-        OptionalValue::Some(action_id)
+        self.propose_or_perform_directly(
+            &Action::SendTransferExecuteEgld(call_data),
+            opt_deadline,
+            opt_signature.into(),
+        )
     }
 
     #[allow_multiple_var_args]
@@ -109,12 +251,12 @@ pub trait ProposeEndpointsModule:
         to: ManagedAddress,
         tokens: PaymentsVec<Self::Api>,
         opt_gas_limit: Option<GasLimit>,
+        opt_deadline: Option<Deadline>,
+        opt_signature: Option<SignatureArg<Self::Api>>,
         function_call: FunctionCall,
-        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
     ) -> OptionalValue<ActionId> {
         require!(!tokens.is_empty(), "No tokens to transfer");
 
-        let proposer = self.get_proposer_no_sig_check(&opt_signature);
         let call_data = EsdtTransferExecuteData {
             to,
             tokens,
@@ -122,16 +264,11 @@ pub trait ProposeEndpointsModule:
             endpoint_name: function_call.function_name,
             arguments: function_call.arg_buffer.into_vec_of_buffers(),
         };
-        let action = Action::SendTransferExecuteEsdt(call_data.clone());
-        let action_id = self.add_action(&action);
-
-        if self.try_perform_esdt_action_directly(&proposer, action_id, &call_data) {
-            return OptionalValue::None;
-        }
-
-        self.check_proposer_role_and_sign(action_id, &action, opt_signature);
-
-        OptionalValue::Some(action_id)
+        self.propose_or_perform_directly(
+            &Action::SendTransferExecuteEsdt(call_data),
+            opt_deadline,
+            opt_signature.into(),
+        )
     }
 
     /// Propose a transaction in which the contract will perform an async call call.
@@ -146,9 +283,10 @@ pub trait ProposeEndpointsModule:
         to: ManagedAddress,
         egld_amount: BigUint,
         opt_gas_limit: Option<GasLimit>,
+        opt_deadline: Option<Deadline>,
+        opt_signature: Option<SignatureArg<Self::Api>>,
         function_call: FunctionCall,
-        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
-    ) -> ActionId {
+    ) -> OptionalValue<ActionId> {
         require!(
             egld_amount > 0 || !function_call.is_empty(),
             "proposed action has no effect"
@@ -162,9 +300,75 @@ pub trait ProposeEndpointsModule:
             arguments: function_call.arg_buffer.into_vec_of_buffers(),
         };
 
-        self.propose_action(&Action::SendAsyncCall(call_data), opt_signature)
+        self.propose_or_perform_directly(
+            &Action::SendAsyncCall(call_data),
+            opt_deadline,
+            opt_signature.into(),
+        )
     }
 
+    /// Propose a call sent through the promises API, to a contract in any shard.
+    /// Unlike the async call, multiple promise calls can be performed in the same transaction, e.g. in a batch.
+    /// The result of each call is tracked by its action ID, see `getPromiseCallStatus`.
+    #[allow_multiple_var_args]
+    #[endpoint(proposePromiseCall)]
+    fn propose_promise_call(
+        &self,
+        to: ManagedAddress,
+        egld_amount: BigUint,
+        gas_limit: GasLimit,
+        opt_deadline: Option<Deadline>,
+        opt_signature: Option<SignatureArg<Self::Api>>,
+        function_call: FunctionCall,
+    ) -> OptionalValue<ActionId> {
+        require!(
+            egld_amount > 0 || !function_call.is_empty(),
+            "proposed action has no effect"
+        );
+
+        let call_data = CallActionData {
+            to,
+            egld_amount,
+            opt_gas_limit: Some(gas_limit),
+            endpoint_name: function_call.function_name,
+            arguments: function_call.arg_buffer.into_vec_of_buffers(),
+        };
+        self.propose_or_perform_directly(
+            &Action::SendPromiseCallEgld(call_data),
+            opt_deadline,
+            opt_signature.into(),
+        )
+    }
+
+    #[allow_multiple_var_args]
+    #[endpoint(proposePromiseCallEsdt)]
+    fn propose_promise_call_esdt(
+        &self,
+        to: ManagedAddress,
+        tokens: PaymentsVec<Self::Api>,
+        gas_limit: GasLimit,
+        opt_deadline: Option<Deadline>,
+        opt_signature: Option<SignatureArg<Self::Api>>,
+        function_call: FunctionCall,
+    ) -> OptionalValue<ActionId> {
+        require!(!tokens.is_empty(), "No tokens to transfer");
+
+        let call_data = EsdtTransferExecuteData {
+            to,
+            tokens,
+            opt_gas_limit: Some(gas_limit),
+            endpoint_name: function_call.function_name,
+            arguments: function_call.arg_buffer.into_vec_of_buffers(),
+        };
+        self.propose_or_perform_directly(
+            &Action::SendPromiseCallEsdt(call_data),
+            opt_deadline,
+            opt_signature.into(),
+        )
+    }
+
+    /// If a module approves the deploy, it is performed right away,
+    /// and the new address can be found in the `contractDeployed` event.
     #[allow_multiple_var_args]
     #[endpoint(proposeSCDeployFromSource)]
     fn propose_sc_deploy_from_source(
@@ -172,16 +376,18 @@ pub trait ProposeEndpointsModule:
         amount: BigUint,
         source: ManagedAddress,
         code_metadata: CodeMetadata,
+        opt_deadline: Option<Deadline>,
         opt_signature: Option<SignatureArg<Self::Api>>,
         arguments: MultiValueEncoded<ManagedBuffer>,
-    ) -> ActionId {
-        self.propose_action(
+    ) -> OptionalValue<ActionId> {
+        self.propose_or_perform_directly(
             &Action::SCDeployFromSource(DeployArgs {
                 amount,
                 source,
                 code_metadata,
                 arguments: arguments.into_vec_of_buffers(),
             }),
+            opt_deadline,
             opt_signature.into(),
         )
     }
@@ -194,10 +400,11 @@ pub trait ProposeEndpointsModule:
         amount: BigUint,
         source: ManagedAddress,
         code_metadata: CodeMetadata,
+        opt_deadline: Option<Deadline>,
         opt_signature: Option<SignatureArg<Self::Api>>,
         arguments: MultiValueEncoded<ManagedBuffer>,
-    ) -> ActionId {
-        self.propose_action(
+    ) -> OptionalValue<ActionId> {
+        self.propose_or_perform_directly(
             &Action::SCUpgradeFromSource {
                 sc_address,
                 args: DeployArgs {
@@ -207,14 +414,60 @@ pub trait ProposeEndpointsModule:
                     arguments: arguments.into_vec_of_buffers(),
                 },
             },
+            opt_deadline,
             opt_signature.into(),
         )
     }
 
+    /// Transfers the ownership of the multisig itself.
+    /// Can only be performed if the multisig is currently its own owner.
+    #[endpoint(proposeChangeOwner)]
+    fn propose_change_owner(
+        &self,
+        new_owner: ManagedAddress,
+        opt_deadline: Option<Deadline>,
+        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
+    ) -> ActionId {
+        require!(!new_owner.is_zero(), "Invalid new owner");
+
+        self.propose_action(&Action::ChangeOwner(new_owner), opt_deadline, opt_signature)
+    }
+
+    /// Transfers the ownership of a contract owned by the multisig, e.g. one it deployed.
+    #[endpoint(proposeChangeManagedContractOwner)]
+    fn propose_change_managed_contract_owner(
+        &self,
+        sc_address: ManagedAddress,
+        new_owner: ManagedAddress,
+        opt_deadline: Option<Deadline>,
+        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
+    ) -> ActionId {
+        require!(
+            self.blockchain().is_smart_contract(&sc_address),
+            "Invalid SC address"
+        );
+        require!(
+            sc_address != self.blockchain().get_sc_address(),
+            "use proposeChangeOwner for the multisig itself"
+        );
+        require!(!new_owner.is_zero(), "Invalid new owner");
+        self.require_same_shard(&sc_address);
+
+        self.propose_action(
+            &Action::ChangeManagedContractOwner {
+                sc_address,
+                new_owner,
+            },
+            opt_deadline,
+            opt_signature,
+        )
+    }
+
     #[endpoint(proposeAddModule)]
     fn propose_add_module(
         &self,
         sc_address: ManagedAddress,
+        opt_deadline: Option<Deadline>,
         opt_signature: OptionalValue<SignatureArg<Self::Api>>,
     ) -> ActionId {
         require!(
@@ -226,29 +479,251 @@ pub trait ProposeEndpointsModule:
         let existing_id = self.module_id().get_id(&sc_address);
         require!(existing_id == NULL_ID, "Module already known");
 
-        self.propose_action(&Action::AddModule(sc_address), opt_signature)
+        self.propose_action(&Action::AddModule(sc_address), opt_deadline, opt_signature)
     }
 
     #[endpoint(proposeRemoveModule)]
     fn propose_remove_module(
         &self,
         sc_address: ManagedAddress,
+        opt_deadline: Option<Deadline>,
         opt_signature: OptionalValue<SignatureArg<Self::Api>>,
     ) -> ActionId {
         let _ = self.module_id().get_id_non_zero(&sc_address);
 
-        self.propose_action(&Action::RemoveModule(sc_address), opt_signature)
+        self.propose_action(
+            &Action::RemoveModule(sc_address),
+            opt_deadline,
+            opt_signature,
+        )
+    }
+
+    /// Modules with notifications enabled are called through `onActionExecuted`
+    /// after executing a transfer they approved.
+    /// The other actions approved through `canExecuteAction` are not notified.
+    /// Can be batched together with adding the module.
+    #[endpoint(proposeSetModuleNotifications)]
+    fn propose_set_module_notifications(
+        &self,
+        sc_address: ManagedAddress,
+        enabled: bool,
+        opt_deadline: Option<Deadline>,
+        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
+    ) -> ActionId {
+        self.propose_action(
+            &Action::SetModuleNotifications {
+                sc_address,
+                enabled,
+            },
+            opt_deadline,
+            opt_signature,
+        )
+    }
+
+    /// Modules with action approval enabled are asked about every call to other contracts
+    /// through `canExecuteAction`, which receives the full action,
+    /// instead of only being asked about transfer-execute actions through `canExecute`.
+    #[endpoint(proposeSetModuleActionApproval)]
+    fn propose_set_module_action_approval(
+        &self,
+        sc_address: ManagedAddress,
+        enabled: bool,
+        opt_deadline: Option<Deadline>,
+        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
+    ) -> ActionId {
+        self.propose_action(
+            &Action::SetModuleActionApproval {
+                sc_address,
+                enabled,
+            },
+            opt_deadline,
+            opt_signature,
+        )
+    }
+
+    /// Pausing only requires a single board member or guardian, but unpausing goes through the board.
+    #[endpoint(proposeUnpause)]
+    fn propose_unpause(
+        &self,
+        opt_deadline: Option<Deadline>,
+        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
+    ) -> ActionId {
+        self.propose_action(&Action::Unpause, opt_deadline, opt_signature)
+    }
+
+    /// Recovery addresses can replace the whole board and quorum,
+    /// if no board member signs anything during the challenge period.
+    /// Replaces the previous recovery addresses, an empty list disables recovery.
+    #[allow_multiple_var_args]
+    #[endpoint(proposeSetRecoveryConfig)]
+    fn propose_set_recovery_config(
+        &self,
+        challenge_period: Timestamp,
+        opt_deadline: Option<Deadline>,
+        opt_signature: Option<SignatureArg<Self::Api>>,
+        recovery_addresses: MultiValueEncoded<ManagedAddress>,
+    ) -> ActionId {
+        self.propose_action(
+            &Action::SetRecoveryConfig {
+                recovery_addresses: recovery_addresses.to_vec(),
+                challenge_period,
+            },
+            opt_deadline,
+            opt_signature.into(),
+        )
+    }
+
+    /// Cannot be lower than the current board size.
+    #[endpoint(proposeSetMaxBoardMembers)]
+    fn propose_set_max_board_members(
+        &self,
+        max_board_members: usize,
+        opt_deadline: Option<Deadline>,
+        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
+    ) -> ActionId {
+        self.propose_action(
+            &Action::SetMaxBoardMembers(max_board_members),
+            opt_deadline,
+            opt_signature,
+        )
+    }
+
+    /// Cannot be lower than the current number of modules.
+    #[endpoint(proposeSetMaxModules)]
+    fn propose_set_max_modules(
+        &self,
+        max_modules: usize,
+        opt_deadline: Option<Deadline>,
+        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
+    ) -> ActionId {
+        self.propose_action(
+            &Action::SetMaxModules(max_modules),
+            opt_deadline,
+            opt_signature,
+        )
+    }
+
+    /// Failed async calls are kept and can be performed again up to `max_retries` times,
+    /// without collecting the signatures again. 0 disables retries.
+    #[endpoint(proposeSetMaxAsyncCallRetries)]
+    fn propose_set_max_async_call_retries(
+        &self,
+        max_retries: usize,
+        opt_deadline: Option<Deadline>,
+        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
+    ) -> ActionId {
+        self.propose_action(
+            &Action::SetMaxAsyncCallRetries(max_retries),
+            opt_deadline,
+            opt_signature,
+        )
+    }
+
+    /// Sets the minimum number of seconds between an action reaching quorum and its execution.
+    /// Applies to all action types that have no override.
+    #[endpoint(proposeSetExecutionDelay)]
+    fn propose_set_execution_delay(
+        &self,
+        delay: Timestamp,
+        opt_deadline: Option<Deadline>,
+        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
+    ) -> ActionId {
+        self.propose_action(
+            &Action::SetExecutionDelay(delay),
+            opt_deadline,
+            opt_signature,
+        )
     }
 
+    /// Overrides the execution delay for a single action type.
+    /// An empty `opt_delay` removes the override, so the default delay is used again.
+    #[endpoint(proposeSetActionKindExecutionDelay)]
+    fn propose_set_action_kind_execution_delay(
+        &self,
+        action_kind: ActionKind,
+        opt_delay: Option<Timestamp>,
+        opt_deadline: Option<Deadline>,
+        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
+    ) -> ActionId {
+        self.propose_action(
+            &Action::SetActionKindExecutionDelay {
+                action_kind,
+                opt_delay,
+            },
+            opt_deadline,
+            opt_signature,
+        )
+    }
+
+    /// Overrides the quorum for a single action type, e.g. to require more signatures for board changes.
+    /// An empty `opt_quorum` removes the override, so the default quorum is used again.
+    /// Only applies to actions proposed afterwards.
+    #[endpoint(proposeChangeActionKindQuorum)]
+    fn propose_change_action_kind_quorum(
+        &self,
+        action_kind: ActionKind,
+        opt_quorum: Option<usize>,
+        opt_deadline: Option<Deadline>,
+        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
+    ) -> ActionId {
+        self.propose_action(
+            &Action::ChangeActionKindQuorum {
+                action_kind,
+                opt_quorum,
+            },
+            opt_deadline,
+            opt_signature,
+        )
+    }
+
+    /// The quorum is expressed as a weight threshold, every board member starts with a weight of 1.
+    #[endpoint(proposeSetBoardMemberWeight)]
+    fn propose_set_board_member_weight(
+        &self,
+        board_member: ManagedAddress,
+        weight: Weight,
+        opt_deadline: Option<Deadline>,
+        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
+    ) -> ActionId {
+        self.propose_action(
+            &Action::SetBoardMemberWeight {
+                board_member,
+                weight,
+            },
+            opt_deadline,
+            opt_signature,
+        )
+    }
+
+    /// The deadline applies to all the actions in the batch.
+    /// The actions are performed in the given order.
+    /// An async call or an upgrade can only be the last action, since it ends the execution.
+    /// If modules approve all the actions, they are executed right away, without creating a batch.
     #[endpoint(proposeBatch)]
-    fn propose_batch(&self, actions: MultiValueEncoded<Action<Self::Api>>) -> GroupId {
+    fn propose_batch(
+        &self,
+        opt_deadline: Option<Deadline>,
+        actions: MultiValueEncoded<Action<Self::Api>>,
+    ) -> OptionalValue<GroupId> {
         let group_id = self.last_action_group_id().get() + 1;
         require!(!actions.is_empty(), "No actions");
 
+        let nr_actions = actions.len();
+        for (index, action) in actions.clone().into_iter().enumerate() {
+            self.require_valid_batch_action_type(&action, index + 1 == nr_actions);
+            self.ensure_valid_transfer_action(&action);
+        }
+        self.require_no_mixed_async_calls(&actions);
+
+        let caller = self.blockchain().get_caller();
+        if self.try_perform_batch_directly(&caller, &actions) {
+            return OptionalValue::None;
+        }
+
         let (caller_id, caller_role) = self.get_caller_id_and_role();
         caller_role.require_can_propose::<Self::Api>();
+        self.record_member_activity(caller_id);
 
-        let mut action_mapper = self.action_mapper();
         let mut action_groups_mapper = self.action_groups(group_id);
         self.action_group_status(group_id)
             .set(ActionStatus::Available);
@@ -259,20 +734,72 @@ pub trait ProposeEndpointsModule:
         );
 
         for action in actions {
-            self.require_valid_action_type(&action);
-            self.ensure_valid_transfer_action(&action);
-
-            let action_id = action_mapper.push(&action);
+            let action_id = self.add_action(&action, opt_deadline);
             if caller_role.can_sign() {
-                let _ = self.action_signer_ids(action_id).insert(caller_id);
+                self.add_action_signer(action_id, caller_id);
+                self.record_board_activity();
+                self.record_quorum_reached(action_id, false);
             }
 
             let _ = action_groups_mapper.insert(action_id);
+            let _ = self.action_group_order(group_id).push(&action_id);
             self.group_for_action(action_id).set(group_id);
         }
 
         self.last_action_group_id().set(group_id);
+        self.record_group_quorum_reached(group_id, false);
+
+        OptionalValue::Some(group_id)
+    }
+
+    /// Returns the ID of the new action, or none if a module approved it and it was already executed.
+    fn propose_or_perform_directly(
+        &self,
+        action: &Action<Self::Api>,
+        opt_deadline: Option<Deadline>,
+        opt_signature: OptionalValue<SignatureArg<Self::Api>>,
+    ) -> OptionalValue<ActionId> {
+        // modules may approve the action based on the proposer, so it has to be verified first
+        let proposer = self.get_proposer(action, &opt_deadline, opt_signature);
+        let action_id = self.add_action(action, opt_deadline);
+
+        if self.try_perform_action_directly(&proposer, action_id, action) {
+            return OptionalValue::None;
+        }
+
+        self.require_proposer_role_and_sign(action_id, &proposer);
+
+        OptionalValue::Some(action_id)
+    }
+
+    /// All actions have to be approved before executing any of them,
+    /// otherwise the batch has to go through the board.
+    fn try_perform_batch_directly(
+        &self,
+        proposer: &ManagedAddress,
+        actions: &MultiValueEncoded<Action<Self::Api>>,
+    ) -> bool {
+        let mut module_ids = ManagedVec::<Self::Api, ModuleId>::new();
+        for action in actions.clone() {
+            let args = CanExecuteArgs {
+                proposer,
+                action: &action,
+            };
+            match self.get_approving_module(&args) {
+                Some(module_id) => module_ids.push(module_id),
+                None => return false,
+            }
+        }
+
+        for (action, module_id) in actions.clone().into_iter().zip(module_ids.iter()) {
+            let action_id = self.add_action(&action, None);
+            let args = CanExecuteArgs {
+                proposer,
+                action: &action,
+            };
+            self.perform_module_approved_action(module_id, action_id, args);
+        }
 
-        group_id
+        true
     }
 }
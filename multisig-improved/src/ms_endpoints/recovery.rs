@@ -0,0 +1,121 @@
+use crate::action_types::execute_action::{BOARD_SIZE_TOO_BIG_ERR_MSG, QUORUM_TOO_BIG_ERR_MSG};
+use crate::common_types::{
+    recovery::PendingRecovery,
+    user_role::{change_user_role, UserRole},
+};
+
+multiversx_sc::imports!();
+
+/// Events of the board replacement are not linked to any action.
+const NO_ACTION_ID: usize = 0;
+
+#[multiversx_sc::module]
+pub trait RecoveryEndpointsModule:
+    crate::common_functions::CommonFunctionsModule
+    + crate::state::StateModule
+    + crate::external::events::EventsModule
+{
+    /// Proposes replacing the whole board and the quorum, in case most board members lost their keys.
+    /// Can only be finished after the challenge period, if no board member signed anything in the meantime.
+    #[endpoint(startRecovery)]
+    fn start_recovery(&self, new_quorum: usize, new_board: MultiValueEncoded<ManagedAddress>) {
+        let caller = self.blockchain().get_caller();
+        self.require_recovery_address(&caller);
+        require!(
+            self.get_active_recovery().is_none(),
+            "recovery already in progress"
+        );
+
+        let new_board = new_board.to_vec();
+        require!(!new_board.is_empty(), "board cannot be empty");
+        require!(
+            new_board.len() <= self.max_board_members().get(),
+            BOARD_SIZE_TOO_BIG_ERR_MSG
+        );
+        require!(
+            new_quorum > 0 && new_quorum <= new_board.len(),
+            "invalid quorum"
+        );
+
+        self.recovery_started_event(&caller, new_quorum, &new_board);
+        self.pending_recovery().set(PendingRecovery {
+            initiator: caller,
+            new_board,
+            new_quorum,
+            start_timestamp: self.blockchain().get_block_timestamp(),
+        });
+    }
+
+    /// Replaces the board once the challenge period has passed.
+    /// Removed board members lose their weights, and all per action type quorums are reset.
+    #[endpoint(finishRecovery)]
+    fn finish_recovery(&self) {
+        let caller = self.blockchain().get_caller();
+        self.require_recovery_address(&caller);
+
+        self.require_storage_migrated();
+
+        let recovery = match self.get_active_recovery() {
+            Some(recovery) => recovery,
+            None => sc_panic!("no active recovery"),
+        };
+        let finish_timestamp = recovery.start_timestamp + self.recovery_challenge_period().get();
+        require!(
+            self.blockchain().get_block_timestamp() >= finish_timestamp,
+            "challenge period has not passed"
+        );
+
+        // the roles change while removing, so the IDs are copied first
+        let mut old_board_ids = ManagedVec::<Self::Api, AddressId>::new();
+        for user_id in self.board_member_ids().iter() {
+            old_board_ids.push(user_id);
+        }
+        for user_id in &old_board_ids {
+            if let Some(board_member) = self.user_ids().get_address(user_id) {
+                change_user_role(self, NO_ACTION_ID, board_member, UserRole::None);
+            }
+        }
+
+        for board_member in &recovery.new_board {
+            change_user_role(self, NO_ACTION_ID, board_member, UserRole::BoardMember);
+        }
+        require!(
+            recovery.new_quorum <= self.total_board_weight().get(),
+            QUORUM_TOO_BIG_ERR_MSG
+        );
+
+        self.quorum().set(recovery.new_quorum);
+        self.action_kind_quorum().clear();
+        self.pending_recovery().clear();
+
+        self.recovery_finished_event(
+            &recovery.initiator,
+            recovery.new_quorum,
+            &recovery.new_board,
+        );
+    }
+
+    /// Returns the pending recovery, unless a board member signed something since it was started.
+    /// Activity in the same block also cancels it, as the order of the transactions is not known.
+    #[view(getActiveRecovery)]
+    fn get_active_recovery(&self) -> Option<PendingRecovery<Self::Api>> {
+        let mapper = self.pending_recovery();
+        if mapper.is_empty() {
+            return None;
+        }
+
+        let recovery = mapper.get();
+        if self.last_board_activity().get() >= recovery.start_timestamp {
+            return None;
+        }
+
+        Some(recovery)
+    }
+
+    fn require_recovery_address(&self, address: &ManagedAddress) {
+        require!(
+            self.recovery_addresses().contains(address),
+            "only recovery addresses can recover the board"
+        );
+    }
+}
@@ -1,6 +1,8 @@
 use crate::common_types::{
     action::{ActionId, ActionStatus, GroupId},
-    signature::SignatureArg,
+    signature::{
+        SignatureArg, SignatureType, COMPRESSED_PUBLIC_KEY_LEN, UNCOMPRESSED_PUBLIC_KEY_LEN,
+    },
 };
 
 multiversx_sc::imports!();
@@ -32,8 +34,13 @@ pub trait SignEndpointsModule:
             );
         }
 
+        let group_quorum_reached_before = group_id != 0 && self.group_quorum_reached(group_id);
         let user_ids = self.check_single_action_signatures(action_id, signatures);
         self.add_signatures(action_id, &user_ids);
+
+        if group_id != 0 {
+            self.record_group_quorum_reached(group_id, group_quorum_reached_before);
+        }
     }
 
     /// Sign all the actions in the given batch
@@ -53,12 +60,15 @@ pub trait SignEndpointsModule:
         let mapper = self.action_groups(group_id);
         require!(!mapper.is_empty(), "Invalid group ID");
 
+        let group_quorum_reached_before = self.group_quorum_reached(group_id);
         let user_ids = self.check_group_signatures(group_id, signatures);
         for action_id in mapper.iter() {
             self.require_action_exists(action_id);
 
             self.add_signatures(action_id, &user_ids);
         }
+
+        self.record_group_quorum_reached(group_id, group_quorum_reached_before);
     }
 
     #[endpoint(signAndPerform)]
@@ -79,27 +89,35 @@ pub trait SignEndpointsModule:
     ) {
         self.sign_batch(group_id, signatures);
 
-        let (_, caller_role) = self.get_caller_id_and_role();
+        let (caller_id, caller_role) = self.get_caller_id_and_role();
+        caller_role.require_can_perform_action::<Self::Api>();
+        self.record_member_activity(caller_id);
+
+        self.perform_batch_in_order(group_id);
+    }
+
+    /// Board members signing with keys that are not derived from their address
+    /// (i.e. Secp256r1 passkeys) have to register the SEC1 encoded public key first.
+    /// Secp256k1 signers may register one as well, otherwise their address is used as key.
+    /// Registering again replaces the previous key.
+    #[endpoint(registerSigningKey)]
+    fn register_signing_key(&self, signature_type: SignatureType, public_key: ManagedBuffer) {
+        let (caller_id, caller_role) = self.get_caller_id_and_role();
+        caller_role.require_can_sign::<Self::Api>();
+
         require!(
-            caller_role.can_perform_action(),
-            "only board members and proposers can perform actions"
+            signature_type.accepts_registered_key(),
+            "Signature type uses the user address as key"
         );
 
-        // Copy action_ids before executing them since perform_action does a swap_remove
-        // clearing the last item
-        let mut action_ids = ManagedVec::<Self::Api, _>::new();
-        for action_id in self.action_groups(group_id).iter() {
-            require!(
-                self.quorum_reached(action_id),
-                "Quorum not reached for action"
-            );
-
-            action_ids.push(action_id);
-        }
+        let key_len = public_key.len();
+        require!(
+            key_len == COMPRESSED_PUBLIC_KEY_LEN || key_len == UNCOMPRESSED_PUBLIC_KEY_LEN,
+            "Invalid public key length"
+        );
 
-        for action_id in &action_ids {
-            let _ = self.perform_action_by_id(action_id);
-        }
+        self.user_signing_key(caller_id, signature_type)
+            .set(public_key);
     }
 
     /// Board members can withdraw their signatures if they no longer desire for the action to be executed.
@@ -148,7 +166,7 @@ pub trait SignEndpointsModule:
         }
 
         for member in board_members_to_remove.iter() {
-            self.action_signer_ids(action_id).swap_remove(&member);
+            self.remove_action_signer(action_id, member);
         }
     }
 }
@@ -1,12 +1,22 @@
-use crate::common_types::action::{ActionId, ActionStatus, GroupId, Nonce};
-use crate::common_types::{action::Action, user_role::UserRole};
+use crate::common_types::action::{
+    ActionId, ActionKind, ActionStatus, Deadline, GroupExecutionStatus, GroupId, Nonce,
+    PromiseCallStatus, Timestamp, Weight,
+};
+use crate::common_types::{
+    action::{Action, ActionExecutionResult, AsyncCallRetryInfo},
+    board::MemberActivity,
+    recovery::PendingRecovery,
+    signature::SignatureType,
+    user_role::UserRole,
+};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 #[multiversx_sc::module]
 pub trait StateModule {
-    /// Minimum number of signatures needed to perform any action.
+    /// Minimum total weight of the signatures needed to perform any action.
+    /// With the default weights, this is the number of signatures.
     #[view(getQuorum)]
     #[storage_mapper("quorum_ids")]
     fn quorum(&self) -> SingleValueMapper<usize>;
@@ -17,18 +27,95 @@ pub trait StateModule {
     #[storage_mapper("userNonce")]
     fn user_nonce(&self, user_id: AddressId) -> SingleValueMapper<Nonce>;
 
+    #[storage_mapper("member_activity")]
+    fn member_activity(&self, user_id: AddressId) -> SingleValueMapper<MemberActivity>;
+
+    #[storage_mapper("userSigningKey")]
+    fn user_signing_key(
+        &self,
+        user_id: AddressId,
+        signature_type: SignatureType,
+    ) -> SingleValueMapper<ManagedBuffer>;
+
+    /// Overrides the default quorum for the given action types.
+    #[storage_mapper("action_kind_quorum")]
+    fn action_kind_quorum(&self) -> MapMapper<ActionKind, usize>;
+
     #[storage_mapper("quorum_for_action")]
     fn quorum_for_action(&self, action_id: ActionId) -> SingleValueMapper<usize>;
 
+    #[view(getActionDeadline)]
+    #[storage_mapper("action_deadline")]
+    fn action_deadline(&self, action_id: ActionId) -> SingleValueMapper<Option<Deadline>>;
+
+    /// Minimum number of seconds between an action reaching quorum and its execution.
+    #[view(getExecutionDelay)]
+    #[storage_mapper("execution_delay")]
+    fn execution_delay(&self) -> SingleValueMapper<Timestamp>;
+
+    /// Overrides the default execution delay for the given action type.
+    #[view(getActionKindExecutionDelay)]
+    #[storage_mapper("action_kind_execution_delay")]
+    fn action_kind_execution_delay(
+        &self,
+        action_kind: ActionKind,
+    ) -> SingleValueMapper<Option<Timestamp>>;
+
+    /// Block timestamp of the moment the action first reached quorum.
+    /// Actions inside a batch use the timestamp of their group instead.
+    #[storage_mapper("quorum_reached_timestamp")]
+    fn quorum_reached_timestamp(&self, action_id: ActionId)
+        -> SingleValueMapper<Option<Timestamp>>;
+
+    #[storage_mapper("group_quorum_reached_timestamp")]
+    fn group_quorum_reached_timestamp(
+        &self,
+        group_id: GroupId,
+    ) -> SingleValueMapper<Option<Timestamp>>;
+
+    /// Block timestamp of the last change of the user's role or weight.
+    /// The delay of the actions signed by the user is counted from this moment at the earliest.
+    #[storage_mapper("signing_power_changed_timestamp")]
+    fn signing_power_changed_timestamp(&self, user_id: AddressId) -> SingleValueMapper<Timestamp>;
+
+    /// Actions that had quorum before the timestamps were recorded count the delay from this moment.
+    #[storage_mapper("quorum_tracking_start_timestamp")]
+    fn quorum_tracking_start_timestamp(&self) -> SingleValueMapper<Timestamp>;
+
     #[storage_mapper("user_role")]
     fn user_id_to_role(&self, user_id: AddressId) -> SingleValueMapper<UserRole>;
 
+    /// Voting weight of a board member. Empty means the default weight.
+    #[storage_mapper("user_weight")]
+    fn user_weight(&self, user_id: AddressId) -> SingleValueMapper<Weight>;
+
+    /// IDs of the current board members, so the board can be listed without going through all users.
+    /// It is kept in sync with the user roles by the contract.
+    #[storage_mapper("board_member_ids")]
+    fn board_member_ids(&self) -> UnorderedSetMapper<AddressId>;
+
     /// Denormalized board member count.
     /// It is kept in sync with the user list by the contract.
     #[view(getNumBoardMembers)]
     #[storage_mapper("num_board_members")]
     fn num_board_members(&self) -> SingleValueMapper<usize>;
 
+    /// Denormalized sum of the weights of all the board members.
+    /// It is kept in sync with the user list and the weights by the contract.
+    #[storage_mapper("total_board_weight")]
+    fn total_board_weight(&self) -> SingleValueMapper<Weight>;
+
+    /// Can be changed by the board, up to a gas-bounded maximum.
+    #[view(getMaxBoardMembers)]
+    #[storage_mapper("max_board_members")]
+    fn max_board_members(&self) -> SingleValueMapper<usize>;
+
+    /// Number of times a failed async call can be performed again, keeping its signatures.
+    /// 0 means failed async calls are cleared right away.
+    #[view(getMaxAsyncCallRetries)]
+    #[storage_mapper("max_async_call_retries")]
+    fn max_async_call_retries(&self) -> SingleValueMapper<usize>;
+
     #[view(getNumGroups)]
     #[storage_mapper("num_groups")]
     fn num_groups(&self) -> SingleValueMapper<usize>;
@@ -39,6 +126,12 @@ pub trait StateModule {
     #[storage_mapper("num_proposers")]
     fn num_proposers(&self) -> SingleValueMapper<usize>;
 
+    /// Denormalized guardian count.
+    /// It is kept in sync with the user list by the contract.
+    #[view(getNumGuardians)]
+    #[storage_mapper("num_guardians")]
+    fn num_guardians(&self) -> SingleValueMapper<usize>;
+
     #[storage_mapper("action_data")]
     fn action_mapper(&self) -> VecMapper<Action<Self::Api>>;
 
@@ -46,6 +139,11 @@ pub trait StateModule {
     #[storage_mapper("action_groups")]
     fn action_groups(&self, group_id: GroupId) -> UnorderedSetMapper<ActionId>;
 
+    /// Actions of the batch in the order they were proposed, including the ones already cleared.
+    /// Cleared once all the actions of the batch are gone.
+    #[storage_mapper("action_group_order")]
+    fn action_group_order(&self, group_id: GroupId) -> VecMapper<ActionId>;
+
     #[view(getLastGroupActionId)]
     #[storage_mapper("last_action_group_id")]
     fn last_action_group_id(&self) -> SingleValueMapper<GroupId>;
@@ -54,9 +152,68 @@ pub trait StateModule {
     #[storage_mapper("action_group_status")]
     fn action_group_status(&self, group_id: GroupId) -> SingleValueMapper<ActionStatus>;
 
+    #[storage_mapper("group_execution_status")]
+    fn group_execution_status(&self, group_id: GroupId) -> SingleValueMapper<GroupExecutionStatus>;
+
+    /// Promise calls of a performed batch whose callback was not received yet.
+    #[storage_mapper("group_pending_promise_calls")]
+    fn group_pending_promise_calls(&self, group_id: GroupId) -> SingleValueMapper<usize>;
+
     #[storage_mapper("group_for_action")]
     fn group_for_action(&self, action_id: ActionId) -> SingleValueMapper<GroupId>;
 
+    #[storage_mapper("async_call_retry")]
+    fn async_call_retry(&self, action_id: ActionId) -> SingleValueMapper<AsyncCallRetryInfo>;
+
+    /// Kept after the action is performed, like the promise call status.
+    #[storage_mapper("action_execution_result")]
+    fn action_execution_result(
+        &self,
+        action_id: ActionId,
+    ) -> SingleValueMapper<ActionExecutionResult<Self::Api>>;
+
+    /// Kept after the action is performed, to track the result of the call.
+    #[storage_mapper("promise_call_status")]
+    fn promise_call_status(&self, action_id: ActionId) -> SingleValueMapper<PromiseCallStatus>;
+
     #[storage_mapper("action_signer_ids")]
     fn action_signer_ids(&self, action_id: ActionId) -> UnorderedSetMapper<AddressId>;
+
+    /// The reverse of `action_signer_ids`, so the signatures of a user can be found without going through all actions.
+    #[storage_mapper("user_signed_actions")]
+    fn user_signed_actions(&self, user_id: AddressId) -> UnorderedSetMapper<ActionId>;
+
+    /// Set by upgrading from a version without `board_member_ids` and `user_signed_actions`,
+    /// until `migrateStorage` indexed all users and actions.
+    #[view(isStorageMigrationPending)]
+    #[storage_mapper("storage_migration_pending")]
+    fn storage_migration_pending(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("migrated_users_count")]
+    fn migrated_users_count(&self) -> SingleValueMapper<AddressId>;
+
+    #[storage_mapper("migrated_actions_count")]
+    fn migrated_actions_count(&self) -> SingleValueMapper<ActionId>;
+
+    #[view(isPaused)]
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+
+    /// Block timestamp of the last signature of a board member, including the implicit proposer signature.
+    #[view(getLastBoardActivity)]
+    #[storage_mapper("last_board_activity")]
+    fn last_board_activity(&self) -> SingleValueMapper<Timestamp>;
+
+    /// Addresses that can propose replacing the whole board, if the board is inactive.
+    #[view(getRecoveryAddresses)]
+    #[storage_mapper("recovery_addresses")]
+    fn recovery_addresses(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    /// Number of seconds the board has to cancel a recovery, by signing anything.
+    #[view(getRecoveryChallengePeriod)]
+    #[storage_mapper("recovery_challenge_period")]
+    fn recovery_challenge_period(&self) -> SingleValueMapper<Timestamp>;
+
+    #[storage_mapper("pending_recovery")]
+    fn pending_recovery(&self) -> SingleValueMapper<PendingRecovery<Self::Api>>;
 }
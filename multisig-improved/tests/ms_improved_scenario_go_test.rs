@@ -0,0 +1,17 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    ScenarioWorld::vm_go()
+}
+
+/// Runs on the Go VM, so the passkey signature goes through the actual Secp256r1 verify hook,
+/// not through the native check used by the Rust VM tests.
+#[test]
+fn secp256r1_passkey_sign_go() {
+    world().run("scenarios/secp256r1_passkey_sign.scen.json");
+}
+
+#[test]
+fn propose_call_encoded_args_go() {
+    world().run("scenarios/propose_call_encoded_args.scen.json");
+}
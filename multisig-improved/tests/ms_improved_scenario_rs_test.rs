@@ -0,0 +1,16 @@
+use multiversx_sc_scenario::*;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.register_contract(
+        "mxsc:output/multisig.mxsc.json",
+        multisig_improved::ContractBuilder,
+    );
+
+    blockchain
+}
+
+#[test]
+fn propose_call_encoded_args_rs() {
+    world().run("scenarios/propose_call_encoded_args.scen.json");
+}
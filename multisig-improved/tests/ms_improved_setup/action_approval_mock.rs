@@ -0,0 +1,31 @@
+use multiversx_sc::contract_base::{CallableContract, ContractBase};
+use multiversx_sc_scenario::DebugApi;
+
+static CAN_EXECUTE_ACTION_FN_NAME: &str = "canExecuteAction";
+
+/// Module that approves any action through `canExecuteAction`.
+/// It does not implement the original `canExecute` view.
+#[derive(Clone, Default)]
+pub struct ActionApprovalMock {}
+
+impl ContractBase for ActionApprovalMock {
+    type Api = DebugApi;
+}
+
+impl CallableContract for ActionApprovalMock {
+    fn call(&self, fn_name: &str) -> bool {
+        if fn_name == CAN_EXECUTE_ACTION_FN_NAME {
+            multiversx_sc::io::finish_multi::<DebugApi, _>(&true);
+
+            return true;
+        }
+
+        false
+    }
+}
+
+impl ActionApprovalMock {
+    pub fn new() -> Self {
+        ActionApprovalMock {}
+    }
+}
@@ -0,0 +1,32 @@
+use multiversx_sc::{
+    api::{ErrorApi, ErrorApiImpl},
+    contract_base::{CallableContract, ContractBase},
+};
+use multiversx_sc_scenario::DebugApi;
+
+static FAIL_FN_NAME: &str = "fail";
+pub static FAIL_ERR_MESSAGE: &[u8] =
+    b"this error message is longer than what the multisig keeps in storage for a failed call";
+
+#[derive(Clone, Default)]
+pub struct FailingCallMock {}
+
+impl ContractBase for FailingCallMock {
+    type Api = DebugApi;
+}
+
+impl CallableContract for FailingCallMock {
+    fn call(&self, fn_name: &str) -> bool {
+        if fn_name == FAIL_FN_NAME {
+            DebugApi::error_api_impl().signal_error(FAIL_ERR_MESSAGE);
+        }
+
+        false
+    }
+}
+
+impl FailingCallMock {
+    pub fn new() -> Self {
+        FailingCallMock {}
+    }
+}
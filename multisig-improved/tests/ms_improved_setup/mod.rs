@@ -1,7 +1,9 @@
 use adder::Adder;
+use ed25519_dalek::{Signer, SigningKey};
 use multisig_improved::{
+    check_signature::CheckSignatureModule,
     common_types::{
-        action::{ActionId, Nonce},
+        action::{Action, ActionId, ActionKind, Deadline, GroupId, Nonce, Timestamp, Weight},
         signature::{ActionType, SignatureArg, SignatureType},
         user_role::UserRole,
     },
@@ -9,18 +11,38 @@ use multisig_improved::{
     ms_endpoints::{
         perform::PerformEndpointsModule, propose::ProposeEndpointsModule, sign::SignEndpointsModule,
     },
+    state::StateModule,
     Multisig,
 };
 use multiversx_sc::{
+    contract_base::{CallableContract, ContractBase},
     imports::OptionalValue,
-    types::{Address, CodeMetadata, FunctionCall, MultiValueEncoded},
+    types::{Address, CodeMetadata, FunctionCall, ManagedAddress, ManagedVec, MultiValueEncoded},
 };
 use multiversx_sc_scenario::{
     imports::{BlockchainStateWrapper, ContractObjWrapper},
     managed_address, managed_biguint, managed_buffer, rust_biguint, DebugApi,
 };
+pub use test_utils::sc_address;
 
+pub mod action_approval_mock;
 pub mod can_execute_mock;
+pub mod failing_call_mock;
+pub mod notified_module_mock;
+
+pub const FIRST_BOARD_MEMBER_KEY_SEED: [u8; 32] = [1u8; 32];
+pub const SECOND_BOARD_MEMBER_KEY_SEED: [u8; 32] = [2u8; 32];
+
+fn to_managed_addresses(addresses: &[Address]) -> ManagedVec<DebugApi, ManagedAddress<DebugApi>> {
+    addresses
+        .iter()
+        .map(|address| managed_address!(address))
+        .collect()
+}
+
+fn shard_of(address: &Address) -> u8 {
+    address.as_bytes()[31] % 3
+}
 
 pub struct MsImprovedSetup<MsImprovedBuilder, AdderBuilder>
 where
@@ -29,7 +51,9 @@ where
 {
     pub b_mock: BlockchainStateWrapper,
     pub first_board_member: Address,
+    pub first_board_member_key: SigningKey,
     pub second_board_member: Address,
+    pub second_board_member_key: SigningKey,
     pub ms_owner: Address,
     pub ms_wrapper: ContractObjWrapper<multisig_improved::ContractObj<DebugApi>, MsImprovedBuilder>,
     pub adder_wrapper: ContractObjWrapper<adder::ContractObj<DebugApi>, AdderBuilder>,
@@ -43,17 +67,30 @@ where
     pub fn new(ms_builder: MsImprovedBuilder, adder_builder: AdderBuilder) -> Self {
         let rust_zero = rust_biguint!(0u64);
         let mut b_mock = BlockchainStateWrapper::new();
-        let first_board_member = b_mock.create_user_account(&rust_zero);
-        let second_board_member = b_mock.create_user_account(&rust_zero);
+        // board members are Ed25519 accounts, their address is their public key
+        let first_board_member_key = SigningKey::from_bytes(&FIRST_BOARD_MEMBER_KEY_SEED);
+        let first_board_member = Address::from(first_board_member_key.verifying_key().to_bytes());
+        b_mock.create_user_account_fixed_address(&first_board_member, &rust_zero);
+
+        let second_board_member_key = SigningKey::from_bytes(&SECOND_BOARD_MEMBER_KEY_SEED);
+        let second_board_member = Address::from(second_board_member_key.verifying_key().to_bytes());
+        b_mock.create_user_account_fixed_address(&second_board_member, &rust_zero);
+
         let ms_owner = b_mock.create_user_account(&rust_zero);
-        let adder_wrapper = b_mock.create_sc_account(
+        let adder_wrapper = b_mock.create_sc_account_fixed_address(
+            &sc_address(b"adder"),
             &rust_zero,
             Some(&first_board_member),
             adder_builder,
             "adder",
         );
-        let ms_wrapper =
-            b_mock.create_sc_account(&rust_zero, Some(&ms_owner), ms_builder, "multisig");
+        let ms_wrapper = b_mock.create_sc_account_fixed_address(
+            &sc_address(b"multisig"),
+            &rust_zero,
+            Some(&ms_owner),
+            ms_builder,
+            "multisig",
+        );
 
         // init adder
         b_mock
@@ -69,20 +106,41 @@ where
                 board.push(managed_address!(&first_board_member));
                 board.push(managed_address!(&second_board_member));
 
-                sc.init(2, board);
+                sc.init(2, None, None, board);
             })
             .assert_ok();
 
         Self {
             b_mock,
             first_board_member,
+            first_board_member_key,
             second_board_member,
+            second_board_member_key,
             ms_owner,
             ms_wrapper,
             adder_wrapper,
         }
     }
 
+    /// Has to be called right before performing the deploy action.
+    /// The generated addresses are skipped until one lands in the multisig's shard.
+    pub fn prepare_deploy_from_ms<CB, ContractObjBuilder>(
+        &mut self,
+        obj_builder: ContractObjBuilder,
+    ) -> ContractObjWrapper<CB, ContractObjBuilder>
+    where
+        CB: ContractBase<Api = DebugApi> + CallableContract + 'static,
+        ContractObjBuilder: 'static + Copy + Fn() -> CB,
+    {
+        let ms_address = self.ms_wrapper.address_ref().clone();
+        loop {
+            let new_sc_wrapper = self.b_mock.prepare_deploy_from_sc(&ms_address, obj_builder);
+            if shard_of(new_sc_wrapper.address_ref()) == shard_of(&ms_address) {
+                return new_sc_wrapper;
+            }
+        }
+    }
+
     pub fn propose_add_board_member(&mut self, board_member: &Address) -> ActionId {
         let mut action_id = 0;
 
@@ -94,6 +152,7 @@ where
                 |sc| {
                     action_id = sc.propose_add_board_member(
                         managed_address!(board_member),
+                        None,
                         OptionalValue::None,
                     );
                 },
@@ -112,8 +171,78 @@ where
                 &self.ms_wrapper,
                 &rust_biguint!(0),
                 |sc| {
-                    action_id =
-                        sc.propose_add_proposer(managed_address!(proposer), OptionalValue::None);
+                    action_id = sc.propose_add_proposer(
+                        managed_address!(proposer),
+                        None,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_add_guardian(&mut self, guardian: &Address) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    action_id = sc.propose_add_guardian(
+                        managed_address!(guardian),
+                        None,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_add_executor(&mut self, executor: &Address) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    action_id = sc.propose_add_executor(
+                        managed_address!(executor),
+                        None,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_add_proposer_with_deadline(
+        &mut self,
+        proposer: &Address,
+        deadline: Deadline,
+    ) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    action_id = sc.propose_add_proposer(
+                        managed_address!(proposer),
+                        Some(deadline),
+                        OptionalValue::None,
+                    );
                 },
             )
             .assert_ok();
@@ -130,7 +259,135 @@ where
                 &self.ms_wrapper,
                 &rust_biguint!(0),
                 |sc| {
-                    action_id = sc.propose_change_quorum(new_quorum, OptionalValue::None);
+                    action_id = sc.propose_change_quorum(new_quorum, None, OptionalValue::None);
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_set_execution_delay(&mut self, delay: Timestamp) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    action_id = sc.propose_set_execution_delay(delay, None, OptionalValue::None);
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_set_max_board_members(&mut self, max_board_members: usize) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    action_id = sc.propose_set_max_board_members(
+                        max_board_members,
+                        None,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_set_max_modules(&mut self, max_modules: usize) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    action_id = sc.propose_set_max_modules(max_modules, None, OptionalValue::None);
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_set_max_async_call_retries(&mut self, max_retries: usize) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    action_id = sc.propose_set_max_async_call_retries(
+                        max_retries,
+                        None,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_change_action_kind_quorum(
+        &mut self,
+        action_kind: ActionKind,
+        opt_quorum: Option<usize>,
+    ) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    action_id = sc.propose_change_action_kind_quorum(
+                        action_kind,
+                        opt_quorum,
+                        None,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_set_board_member_weight(
+        &mut self,
+        board_member: &Address,
+        weight: Weight,
+    ) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    action_id = sc.propose_set_board_member_weight(
+                        managed_address!(board_member),
+                        weight,
+                        None,
+                        OptionalValue::None,
+                    );
                 },
             )
             .assert_ok();
@@ -163,8 +420,9 @@ where
                             managed_address!(to),
                             managed_biguint!(egld_amount),
                             None,
+                            None,
+                            None,
                             function_call,
-                            OptionalValue::None,
                         )
                         .into_option()
                         .unwrap();
@@ -175,15 +433,13 @@ where
         action_id
     }
 
-    pub fn propose_async_call(
+    /// Expects a module to approve the action, so it is executed without signatures.
+    pub fn propose_transfer_execute_directly(
         &mut self,
         to: &Address,
-        egld_amount: u64,
         function_name: &[u8],
         args: Vec<&[u8]>,
-    ) -> ActionId {
-        let mut action_id = 0;
-
+    ) {
         self.b_mock
             .execute_tx(
                 &self.first_board_member,
@@ -195,21 +451,21 @@ where
                         function_call = function_call.argument(&arg);
                     }
 
-                    action_id = sc.propose_async_call(
+                    let result = sc.propose_transfer_execute(
                         managed_address!(to),
-                        managed_biguint!(egld_amount),
+                        managed_biguint!(0),
+                        None,
+                        None,
                         None,
                         function_call,
-                        OptionalValue::None,
                     );
+                    assert!(result.is_none());
                 },
             )
             .assert_ok();
-
-        action_id
     }
 
-    pub fn propose_remove_user(&mut self, user: &Address) -> ActionId {
+    pub fn propose_add_module(&mut self, sc_address: &Address) -> ActionId {
         let mut action_id = 0;
 
         self.b_mock
@@ -218,7 +474,11 @@ where
                 &self.ms_wrapper,
                 &rust_biguint!(0),
                 |sc| {
-                    action_id = sc.propose_remove_user(managed_address!(user), OptionalValue::None);
+                    action_id = sc.propose_add_module(
+                        managed_address!(sc_address),
+                        None,
+                        OptionalValue::None,
+                    );
                 },
             )
             .assert_ok();
@@ -226,13 +486,7 @@ where
         action_id
     }
 
-    pub fn propose_sc_deploy_from_source(
-        &mut self,
-        egld_amount: u64,
-        source: &Address,
-        code_metadata: CodeMetadata,
-        arguments: Vec<&[u8]>,
-    ) -> ActionId {
+    pub fn propose_remove_module(&mut self, sc_address: &Address) -> ActionId {
         let mut action_id = 0;
 
         self.b_mock
@@ -241,17 +495,10 @@ where
                 &self.ms_wrapper,
                 &rust_biguint!(0),
                 |sc| {
-                    let mut args = MultiValueEncoded::new();
-                    for arg in arguments {
-                        args.push(managed_buffer!(arg));
-                    }
-
-                    action_id = sc.propose_sc_deploy_from_source(
-                        managed_biguint!(egld_amount),
-                        managed_address!(source),
-                        code_metadata,
+                    action_id = sc.propose_remove_module(
+                        managed_address!(sc_address),
                         None,
-                        args,
+                        OptionalValue::None,
                     );
                 },
             )
@@ -260,13 +507,10 @@ where
         action_id
     }
 
-    pub fn propose_sc_upgrade_from_source(
+    pub fn propose_set_module_notifications(
         &mut self,
         sc_address: &Address,
-        egld_amount: u64,
-        source: &Address,
-        code_metadata: CodeMetadata,
-        arguments: Vec<&[u8]>,
+        enabled: bool,
     ) -> ActionId {
         let mut action_id = 0;
 
@@ -276,18 +520,11 @@ where
                 &self.ms_wrapper,
                 &rust_biguint!(0),
                 |sc| {
-                    let mut args = MultiValueEncoded::new();
-                    for arg in arguments {
-                        args.push(managed_buffer!(arg));
-                    }
-
-                    action_id = sc.propose_sc_upgrade_from_source(
+                    action_id = sc.propose_set_module_notifications(
                         managed_address!(sc_address),
-                        managed_biguint!(egld_amount),
-                        managed_address!(source),
-                        code_metadata,
+                        enabled,
                         None,
-                        args,
+                        OptionalValue::None,
                     );
                 },
             )
@@ -296,49 +533,463 @@ where
         action_id
     }
 
-    pub fn perform(&mut self, action_id: ActionId) {
+    pub fn propose_set_module_action_approval(
+        &mut self,
+        sc_address: &Address,
+        enabled: bool,
+    ) -> ActionId {
+        let mut action_id = 0;
+
         self.b_mock
             .execute_tx(
                 &self.first_board_member,
                 &self.ms_wrapper,
                 &rust_biguint!(0),
                 |sc| {
-                    let _ = sc.perform_action_endpoint(action_id);
+                    action_id = sc.propose_set_module_action_approval(
+                        managed_address!(sc_address),
+                        enabled,
+                        None,
+                        OptionalValue::None,
+                    );
                 },
             )
             .assert_ok();
+
+        action_id
     }
 
-    pub fn perform_and_expect_err(&mut self, action_id: ActionId, err_message: &str) {
+    pub fn propose_unpause(&mut self) -> ActionId {
+        let mut action_id = 0;
+
         self.b_mock
             .execute_tx(
                 &self.first_board_member,
                 &self.ms_wrapper,
                 &rust_biguint!(0),
                 |sc| {
-                    let _ = sc.perform_action_endpoint(action_id);
+                    action_id = sc.propose_unpause(None, OptionalValue::None);
                 },
             )
-            .assert_user_error(err_message);
+            .assert_ok();
+
+        action_id
     }
 
-    pub fn sign(&mut self, action_id: ActionId, signer_nonce: Nonce) {
-        let signer_addr = self.second_board_member.clone();
+    pub fn propose_set_recovery_config(
+        &mut self,
+        challenge_period: Timestamp,
+        recovery_addresses: &[Address],
+    ) -> ActionId {
+        let mut action_id = 0;
 
         self.b_mock
             .execute_tx(
-                &self.second_board_member,
+                &self.first_board_member,
                 &self.ms_wrapper,
                 &rust_biguint!(0),
                 |sc| {
-                    let mut signatures = MultiValueEncoded::new();
-                    signatures.push(SignatureArg {
-                        user_address: managed_address!(&signer_addr),
-                        nonce: signer_nonce,
-                        action_type: ActionType::SimpleAction,
-                        raw_sig_bytes: managed_buffer!(b"signature"),
-                        signature_type: SignatureType::Ed25519, // unused
-                    });
+                    let mut addresses = MultiValueEncoded::new();
+                    for address in recovery_addresses {
+                        addresses.push(managed_address!(address));
+                    }
+
+                    action_id =
+                        sc.propose_set_recovery_config(challenge_period, None, None, addresses);
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_async_call(
+        &mut self,
+        to: &Address,
+        egld_amount: u64,
+        function_name: &[u8],
+        args: Vec<&[u8]>,
+    ) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let mut function_call = FunctionCall::new(function_name);
+                    for arg in args {
+                        function_call = function_call.argument(&arg);
+                    }
+
+                    action_id = sc
+                        .propose_async_call(
+                            managed_address!(to),
+                            managed_biguint!(egld_amount),
+                            None,
+                            None,
+                            None,
+                            function_call,
+                        )
+                        .into_option()
+                        .unwrap();
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_promise_call(
+        &mut self,
+        to: &Address,
+        egld_amount: u64,
+        gas_limit: u64,
+        function_name: &[u8],
+        args: Vec<&[u8]>,
+    ) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let mut function_call = FunctionCall::new(function_name);
+                    for arg in args {
+                        function_call = function_call.argument(&arg);
+                    }
+
+                    action_id = sc
+                        .propose_promise_call(
+                            managed_address!(to),
+                            managed_biguint!(egld_amount),
+                            gas_limit,
+                            None,
+                            None,
+                            function_call,
+                        )
+                        .into_option()
+                        .unwrap();
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_remove_user(&mut self, user: &Address) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    action_id =
+                        sc.propose_remove_user(managed_address!(user), None, OptionalValue::None);
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_remove_proposer(&mut self, user: &Address) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    action_id = sc.propose_remove_proposer(
+                        managed_address!(user),
+                        None,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_demote_to_proposer(&mut self, user: &Address) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    action_id = sc.propose_demote_to_proposer(
+                        managed_address!(user),
+                        None,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_promote_to_board_member(&mut self, user: &Address) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    action_id = sc.propose_promote_to_board_member(
+                        managed_address!(user),
+                        None,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_replace_board_member(
+        &mut self,
+        old_board_member: &Address,
+        new_board_member: &Address,
+        migrate_signatures: bool,
+    ) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    action_id = sc.propose_replace_board_member(
+                        managed_address!(old_board_member),
+                        managed_address!(new_board_member),
+                        migrate_signatures,
+                        None,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_reconfigure_board(
+        &mut self,
+        add: &[Address],
+        remove: &[Address],
+        proposers_add: &[Address],
+        proposers_remove: &[Address],
+        new_quorum: usize,
+    ) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    action_id = sc.propose_reconfigure_board(
+                        to_managed_addresses(add),
+                        to_managed_addresses(remove),
+                        to_managed_addresses(proposers_add),
+                        to_managed_addresses(proposers_remove),
+                        new_quorum,
+                        None,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_change_owner(&mut self, new_owner: &Address) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    action_id = sc.propose_change_owner(
+                        managed_address!(new_owner),
+                        None,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_change_managed_contract_owner(
+        &mut self,
+        sc_address: &Address,
+        new_owner: &Address,
+    ) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    action_id = sc.propose_change_managed_contract_owner(
+                        managed_address!(sc_address),
+                        managed_address!(new_owner),
+                        None,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_sc_deploy_from_source(
+        &mut self,
+        egld_amount: u64,
+        source: &Address,
+        code_metadata: CodeMetadata,
+        arguments: Vec<&[u8]>,
+    ) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let mut args = MultiValueEncoded::new();
+                    for arg in arguments {
+                        args.push(managed_buffer!(arg));
+                    }
+
+                    action_id = sc
+                        .propose_sc_deploy_from_source(
+                            managed_biguint!(egld_amount),
+                            managed_address!(source),
+                            code_metadata,
+                            None,
+                            None,
+                            args,
+                        )
+                        .into_option()
+                        .unwrap();
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_sc_upgrade_from_source(
+        &mut self,
+        sc_address: &Address,
+        egld_amount: u64,
+        source: &Address,
+        code_metadata: CodeMetadata,
+        arguments: Vec<&[u8]>,
+    ) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let mut args = MultiValueEncoded::new();
+                    for arg in arguments {
+                        args.push(managed_buffer!(arg));
+                    }
+
+                    action_id = sc
+                        .propose_sc_upgrade_from_source(
+                            managed_address!(sc_address),
+                            managed_biguint!(egld_amount),
+                            managed_address!(source),
+                            code_metadata,
+                            None,
+                            None,
+                            args,
+                        )
+                        .into_option()
+                        .unwrap();
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn perform(&mut self, action_id: ActionId) {
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let _ = sc.perform_action_endpoint(action_id);
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn perform_and_expect_err(&mut self, action_id: ActionId, err_message: &str) {
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let _ = sc.perform_action_endpoint(action_id);
+                },
+            )
+            .assert_user_error(err_message);
+    }
+
+    pub fn sign(&mut self, action_id: ActionId, signer_nonce: Nonce) {
+        let signer_addr = self.second_board_member.clone();
+        let signature = self.ed25519_action_signature(&signer_addr, action_id, signer_nonce);
+
+        self.b_mock
+            .execute_tx(
+                &self.second_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let mut signatures = MultiValueEncoded::new();
+                    signatures.push(SignatureArg {
+                        user_address: managed_address!(&signer_addr),
+                        nonce: signer_nonce,
+                        action_type: ActionType::SimpleAction,
+                        raw_sig_bytes: managed_buffer!(&signature),
+                        signature_type: SignatureType::Ed25519,
+                    });
 
                     sc.sign(action_id, signatures);
                 },
@@ -346,6 +997,120 @@ where
             .assert_ok();
     }
 
+    pub fn action_hash(
+        &mut self,
+        signer: &Address,
+        action_id: ActionId,
+        signer_nonce: Nonce,
+    ) -> [u8; 32] {
+        let mut hash = [0u8; 32];
+        self.b_mock
+            .execute_query(&self.ms_wrapper, |sc| {
+                let action = sc.action_mapper().get(action_id);
+                hash = sc
+                    .serialize_and_hash_action(&action, &managed_address!(signer), signer_nonce)
+                    .to_byte_array();
+            })
+            .assert_ok();
+
+        hash
+    }
+
+    pub fn group_hash(
+        &mut self,
+        signer: &Address,
+        group_id: GroupId,
+        signer_nonce: Nonce,
+    ) -> [u8; 32] {
+        let mut hash = [0u8; 32];
+        self.b_mock
+            .execute_query(&self.ms_wrapper, |sc| {
+                hash = sc
+                    .serialize_and_hash_group(group_id, &managed_address!(signer), signer_nonce)
+                    .to_byte_array();
+            })
+            .assert_ok();
+
+        hash
+    }
+
+    pub fn ed25519_action_signature(
+        &mut self,
+        signer: &Address,
+        action_id: ActionId,
+        signer_nonce: Nonce,
+    ) -> Vec<u8> {
+        let hash = self.action_hash(signer, action_id, signer_nonce);
+        self.board_member_key(signer)
+            .sign(&hash)
+            .to_bytes()
+            .to_vec()
+    }
+
+    pub fn ed25519_add_proposer_proposal_signature(
+        &mut self,
+        signer: &Address,
+        proposer: &Address,
+        opt_deadline: Option<Deadline>,
+        signer_nonce: Nonce,
+    ) -> Vec<u8> {
+        let mut hash = [0u8; 32];
+        self.b_mock
+            .execute_query(&self.ms_wrapper, |sc| {
+                let action = Action::AddProposer(managed_address!(proposer));
+                hash = sc
+                    .serialize_and_hash_proposal(
+                        &action,
+                        &opt_deadline,
+                        &managed_address!(signer),
+                        signer_nonce,
+                    )
+                    .to_byte_array();
+            })
+            .assert_ok();
+
+        self.board_member_key(signer)
+            .sign(&hash)
+            .to_bytes()
+            .to_vec()
+    }
+
+    pub fn ed25519_group_signature(
+        &mut self,
+        signer: &Address,
+        group_id: GroupId,
+        signer_nonce: Nonce,
+    ) -> Vec<u8> {
+        let hash = self.group_hash(signer, group_id, signer_nonce);
+        self.board_member_key(signer)
+            .sign(&hash)
+            .to_bytes()
+            .to_vec()
+    }
+
+    fn board_member_key(&self, board_member: &Address) -> &SigningKey {
+        if board_member == &self.first_board_member {
+            &self.first_board_member_key
+        } else if board_member == &self.second_board_member {
+            &self.second_board_member_key
+        } else {
+            panic!("No Ed25519 key for this user");
+        }
+    }
+
+    pub fn register_signing_key(
+        &mut self,
+        user: &Address,
+        signature_type: SignatureType,
+        public_key: &[u8],
+    ) {
+        self.b_mock
+            .execute_tx(user, &self.ms_wrapper, &rust_biguint!(0), |sc| {
+                sc.register_signing_key(signature_type, managed_buffer!(public_key));
+            })
+            .assert_ok();
+    }
+
     pub fn expect_user_role(&mut self, user: &Address, expected_user_role: UserRole) {
         self.b_mock
             .execute_query(&self.ms_wrapper, |sc| {
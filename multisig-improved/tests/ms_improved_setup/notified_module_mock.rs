@@ -0,0 +1,67 @@
+use std::cell::RefCell;
+
+use multisig_improved::common_types::action::ActionId;
+use multiversx_sc::{
+    api::{EndpointArgumentApi, EndpointArgumentApiImpl, ErrorApi, ErrorApiImpl},
+    contract_base::{CallableContract, ContractBase},
+};
+use multiversx_sc_scenario::DebugApi;
+
+static CAN_EXECUTE_FN_NAME: &str = "canExecute";
+static ON_ACTION_EXECUTED_FN_NAME: &str = "onActionExecuted";
+
+thread_local! {
+    static NOTIFIED_ACTION_IDS: RefCell<Vec<ActionId>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Module that implements `onActionExecuted`, keeping track of the notified action IDs.
+/// Modules that do not approve actions must never be notified.
+#[derive(Clone, Default)]
+pub struct NotifiedModuleMock {
+    approves_actions: bool,
+}
+
+impl ContractBase for NotifiedModuleMock {
+    type Api = DebugApi;
+}
+
+impl CallableContract for NotifiedModuleMock {
+    fn call(&self, fn_name: &str) -> bool {
+        if fn_name == CAN_EXECUTE_FN_NAME {
+            multiversx_sc::io::finish_multi::<DebugApi, _>(&self.approves_actions);
+
+            return true;
+        }
+
+        if fn_name == ON_ACTION_EXECUTED_FN_NAME {
+            if !self.approves_actions {
+                DebugApi::error_api_impl().signal_error(b"notified for rejected action");
+            }
+
+            let action_id = DebugApi::argument_api_impl().get_argument_u64(0) as ActionId;
+            NOTIFIED_ACTION_IDS.with(|ids| ids.borrow_mut().push(action_id));
+
+            return true;
+        }
+
+        false
+    }
+}
+
+impl NotifiedModuleMock {
+    pub fn approving() -> Self {
+        NotifiedModuleMock {
+            approves_actions: true,
+        }
+    }
+
+    pub fn rejecting() -> Self {
+        NotifiedModuleMock {
+            approves_actions: false,
+        }
+    }
+
+    pub fn notified_action_ids() -> Vec<ActionId> {
+        NOTIFIED_ACTION_IDS.with(|ids| ids.borrow().clone())
+    }
+}
@@ -3,30 +3,107 @@ pub mod ms_improved_setup;
 use adder::Adder;
 use can_execute_mock::CanExecuteMock;
 use factorial::Factorial;
-use ms_improved_setup::*;
+use k256::ecdsa::signature::hazmat::PrehashSigner;
+use ms_improved_setup::{
+    action_approval_mock::ActionApprovalMock,
+    failing_call_mock::{FailingCallMock, FAIL_ERR_MESSAGE},
+    notified_module_mock::NotifiedModuleMock,
+    *,
+};
 use multisig_improved::{
+    action_types::{
+        execute_action::{
+            DEFAULT_MAX_BOARD_MEMBERS, DEFAULT_MAX_MODULES, MAX_BOARD_MEMBERS_LIMIT,
+            MAX_MODULES_LIMIT, MIN_RECOVERY_CHALLENGE_PERIOD,
+        },
+        external_module::{ExternalModuleModule, MAX_RECENT_MODULE_EXECUTIONS},
+    },
+    check_signature::CheckSignatureModule,
+    common_functions::CommonFunctionsModule,
     common_types::{
-        action::{Action, CallActionData},
+        action::{
+            Action, ActionKind, AsyncCallRetryInfo, CallActionData, Deadline, DeployArgs,
+            GroupExecutionStatus, PromiseCallStatus,
+        },
+        board::MemberActivity,
         signature::{ActionType, SignatureArg, SignatureType},
         user_role::UserRole,
+        webauthn::{base64url_encode_digest, WebAuthnSignature},
     },
     external::views::ViewsModule,
     ms_endpoints::{
-        discard::DiscardEndpointsModule, perform::PerformEndpointsModule,
-        propose::ProposeEndpointsModule, sign::SignEndpointsModule,
+        callbacks::MAX_STORED_ERR_MESSAGE_LEN, discard::DiscardEndpointsModule,
+        migration::MigrationEndpointsModule, pause::PauseEndpointsModule,
+        perform::PerformEndpointsModule, propose::ProposeEndpointsModule,
+        recovery::RecoveryEndpointsModule, sign::SignEndpointsModule,
     },
+    state::StateModule,
     Multisig,
 };
 use multiversx_sc::{
     codec::TopEncode,
+    contract_base::ContractBase,
     imports::OptionalValue,
+    storage::mappers::StorageClearable,
     types::{
-        CodeMetadata, FunctionCall, ManagedArgBuffer, ManagedBuffer, ManagedVec, MultiValueEncoded,
+        Address, CodeMetadata, FunctionCall, ManagedArgBuffer, ManagedBuffer, ManagedVec,
+        MultiValueEncoded,
     },
 };
 use multiversx_sc_scenario::{
     managed_address, managed_biguint, managed_buffer, rust_biguint, DebugApi,
 };
+use p256::ecdsa::{signature::Signer, SigningKey};
+use sha2::{Digest, Sha256};
+
+struct WebAuthnVector {
+    authenticator_data: Vec<u8>,
+    client_data_json: Vec<u8>,
+    signature: Vec<u8>,
+}
+
+fn passkey_signing_key() -> SigningKey {
+    SigningKey::from_slice(&[0x2Au8; 32]).unwrap()
+}
+
+fn passkey_public_key(signing_key: &SigningKey) -> Vec<u8> {
+    signing_key
+        .verifying_key()
+        .to_encoded_point(true)
+        .as_bytes()
+        .to_vec()
+}
+
+fn webauthn_sign(signing_key: &SigningKey, challenge: &[u8; 32]) -> WebAuthnVector {
+    let mut authenticator_data = vec![0x49u8; 32]; // RP ID hash
+    authenticator_data.push(0x05); // user present + user verified
+    authenticator_data.extend_from_slice(&[0, 0, 0, 1]);
+
+    let encoded_challenge = base64url_encode_digest(challenge);
+    let client_data_json = format!(
+        r#"{{"type":"webauthn.get","challenge":"{}","origin":"https://wallet.multiversx.com","crossOrigin":false}}"#,
+        std::str::from_utf8(&encoded_challenge).unwrap()
+    );
+
+    let mut signed_message = authenticator_data.clone();
+    signed_message.extend_from_slice(&Sha256::digest(client_data_json.as_bytes()));
+    let signature: p256::ecdsa::Signature = signing_key.sign(&signed_message);
+
+    WebAuthnVector {
+        authenticator_data,
+        client_data_json: client_data_json.into_bytes(),
+        signature: signature.to_der().as_bytes().to_vec(),
+    }
+}
+
+fn secp256k1_signing_key() -> k256::ecdsa::SigningKey {
+    k256::ecdsa::SigningKey::from_slice(&[0x2Bu8; 32]).unwrap()
+}
+
+fn secp256k1_sign(signing_key: &k256::ecdsa::SigningKey, hash: &[u8; 32]) -> Vec<u8> {
+    let signature: k256::ecdsa::Signature = signing_key.sign_prehash(hash).unwrap();
+    signature.to_der().as_bytes().to_vec()
+}
 
 #[test]
 fn init_test() {
@@ -121,7 +198,7 @@ fn try_remove_all_board_members_test() {
 
     let action_id = ms_setup.propose_remove_user(&ms_setup.first_board_member.clone());
     ms_setup.sign(action_id, 0);
-    ms_setup.perform_and_expect_err(action_id, "quorum cannot exceed board size");
+    ms_setup.perform_and_expect_err(action_id, "quorum cannot exceed total board weight");
 }
 
 #[test]
@@ -132,7 +209,7 @@ fn change_quorum_test() {
     let new_quorum = 3;
     let action_id = ms_setup.propose_change_quorum(new_quorum);
     ms_setup.sign(action_id, 0);
-    ms_setup.perform_and_expect_err(action_id, "quorum cannot exceed board size");
+    ms_setup.perform_and_expect_err(action_id, "quorum cannot exceed total board weight");
 
     // try discard before unsigning
     ms_setup
@@ -263,8 +340,9 @@ fn transfer_execute_to_user_test() {
                         managed_address!(&new_user),
                         managed_biguint!(0),
                         None,
+                        None,
+                        None,
                         FunctionCall::empty(),
-                        OptionalValue::None,
                     )
                     .into_option()
                     .unwrap();
@@ -337,12 +415,16 @@ fn transfer_execute_batch_test() {
                 multi_action_vec.push(single_action.clone());
                 multi_action_vec.push(single_action);
 
-                group_id = sc.propose_batch(multi_action_vec);
+                group_id = sc
+                    .propose_batch(None, multi_action_vec)
+                    .into_option()
+                    .unwrap();
             },
         )
         .assert_ok();
 
     let signer_addr = ms_setup.second_board_member.clone();
+    let signature = ms_setup.ed25519_group_signature(&signer_addr, group_id, 0);
     ms_setup
         .b_mock
         .execute_tx(
@@ -355,8 +437,8 @@ fn transfer_execute_batch_test() {
                     user_address: managed_address!(&signer_addr),
                     nonce: 0,
                     action_type: ActionType::Group,
-                    raw_sig_bytes: managed_buffer!(b"signature"),
-                    signature_type: SignatureType::Ed25519, // unused
+                    raw_sig_bytes: managed_buffer!(&signature),
+                    signature_type: SignatureType::Ed25519,
                 });
 
                 sc.sign_batch_and_perform(group_id, signatures)
@@ -372,6 +454,80 @@ fn transfer_execute_batch_test() {
         .assert_ok();
 }
 
+#[test]
+fn batch_requires_quorum_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+
+    let first_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let second_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let mut group_id = 0;
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut actions = MultiValueEncoded::new();
+                actions.push(Action::AddProposer(managed_address!(&first_proposer)));
+                actions.push(Action::AddProposer(managed_address!(&second_proposer)));
+
+                group_id = sc.propose_batch(None, actions).into_option().unwrap();
+            },
+        )
+        .assert_ok();
+
+    // batch actions get the quorum of their type, like single actions
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            for action_id in sc.action_groups(group_id).iter() {
+                assert_eq!(sc.quorum_for_action(action_id).get(), 2);
+                assert!(!sc.quorum_reached(action_id));
+            }
+        })
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.perform_batch(group_id);
+            },
+        )
+        .assert_user_error("quorum has not been reached");
+    ms_setup.expect_user_role(&first_proposer, UserRole::None);
+    ms_setup.expect_user_role(&second_proposer, UserRole::None);
+
+    let signer_addr = ms_setup.second_board_member.clone();
+    let signature = ms_setup.ed25519_group_signature(&signer_addr, group_id, 0);
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.second_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut signatures = MultiValueEncoded::new();
+                signatures.push(SignatureArg {
+                    user_address: managed_address!(&signer_addr),
+                    nonce: 0,
+                    action_type: ActionType::Group,
+                    raw_sig_bytes: managed_buffer!(&signature),
+                    signature_type: SignatureType::Ed25519,
+                });
+
+                sc.sign_batch_and_perform(group_id, signatures)
+            },
+        )
+        .assert_ok();
+    ms_setup.expect_user_role(&first_proposer, UserRole::Proposer);
+    ms_setup.expect_user_role(&second_proposer, UserRole::Proposer);
+}
+
 #[test]
 fn async_call_to_sc_test() {
     let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
@@ -398,10 +554,6 @@ fn async_call_to_sc_test() {
 fn deploy_and_upgrade_from_source_test() {
     let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
 
-    let new_adder_wrapper = ms_setup
-        .b_mock
-        .prepare_deploy_from_sc(ms_setup.ms_wrapper.address_ref(), adder::contract_obj);
-
     let args = [&[5u8][..]].to_vec();
     let action_id = ms_setup.propose_sc_deploy_from_source(
         0,
@@ -410,6 +562,8 @@ fn deploy_and_upgrade_from_source_test() {
         args,
     );
     ms_setup.sign(action_id, 0);
+
+    let new_adder_wrapper = ms_setup.prepare_deploy_from_ms(adder::contract_obj);
     ms_setup.perform(action_id);
 
     let args = [&[5u8][..]].to_vec();
@@ -425,7 +579,8 @@ fn deploy_and_upgrade_from_source_test() {
         })
         .assert_ok();
 
-    let factorial_wrapper = ms_setup.b_mock.create_sc_account(
+    let factorial_wrapper = ms_setup.b_mock.create_sc_account_fixed_address(
+        &sc_address(b"factorial"),
         &rust_biguint!(0),
         Some(&ms_setup.first_board_member),
         factorial::contract_obj,
@@ -457,7 +612,8 @@ fn deploy_and_upgrade_from_source_test() {
 #[test]
 fn add_can_execute_module_test() {
     let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
-    let can_execute_mock = ms_setup.b_mock.create_sc_account(
+    let can_execute_mock = ms_setup.b_mock.create_sc_account_fixed_address(
+        &sc_address(b"can-execute-mock"),
         &rust_biguint!(0),
         Some(&ms_setup.ms_owner),
         CanExecuteMock::new,
@@ -473,6 +629,7 @@ fn add_can_execute_module_test() {
             |sc| {
                 sc.propose_add_module(
                     managed_address!(can_execute_mock.address_ref()),
+                    None,
                     OptionalValue::None,
                 );
             },
@@ -494,6 +651,7 @@ fn add_can_execute_module_test() {
 
     // other user sign
     let other_board_member = ms_setup.second_board_member.clone();
+    let signature = ms_setup.ed25519_action_signature(&other_board_member, 1, 0);
     ms_setup
         .b_mock
         .execute_tx(
@@ -506,8 +664,8 @@ fn add_can_execute_module_test() {
                     user_address: managed_address!(&other_board_member),
                     nonce: 0,
                     action_type: ActionType::SimpleAction,
-                    signature_type: SignatureType::Ed25519, // unused
-                    raw_sig_bytes: managed_buffer!(b"signature"),
+                    signature_type: SignatureType::Ed25519,
+                    raw_sig_bytes: managed_buffer!(&signature),
                 });
 
                 sc.sign(1, signatures)
@@ -529,7 +687,7 @@ fn add_can_execute_module_test() {
         .assert_ok();
 
     // execute action via canExecute -> no signatures required
-    let adder_addr = ms_setup.adder_wrapper.address_ref();
+    let adder_addr = ms_setup.adder_wrapper.address_ref().clone();
     ms_setup
         .b_mock
         .execute_tx(
@@ -542,14 +700,15 @@ fn add_can_execute_module_test() {
                     .push_arg::<multiversx_sc::types::BigUint<DebugApi>>(managed_biguint!(5));
 
                 let func_result = sc.propose_transfer_execute(
-                    managed_address!(adder_addr),
+                    managed_address!(&adder_addr),
                     managed_biguint!(0),
                     Option::None,
+                    None,
+                    None,
                     FunctionCall {
                         function_name: managed_buffer!(b"add"),
                         arg_buffer: add_function_args,
                     },
-                    OptionalValue::None,
                 );
                 assert!(func_result.is_none());
             },
@@ -574,6 +733,7 @@ fn add_can_execute_module_test() {
             |sc| {
                 sc.propose_remove_module(
                     managed_address!(can_execute_mock.address_ref()),
+                    None,
                     OptionalValue::None,
                 );
             },
@@ -581,6 +741,7 @@ fn add_can_execute_module_test() {
         .assert_ok();
 
     // ID is 3, as even though previous proposal didn't actually register, an action ID is still used
+    let signature = ms_setup.ed25519_action_signature(&other_board_member, 3, 1);
     ms_setup
         .b_mock
         .execute_tx(
@@ -593,8 +754,8 @@ fn add_can_execute_module_test() {
                     user_address: managed_address!(&other_board_member),
                     nonce: 1,
                     action_type: ActionType::SimpleAction,
-                    signature_type: SignatureType::Ed25519, // unused
-                    raw_sig_bytes: managed_buffer!(b"signature"),
+                    signature_type: SignatureType::Ed25519,
+                    raw_sig_bytes: managed_buffer!(&signature),
                 });
 
                 sc.sign(3, signatures)
@@ -627,14 +788,15 @@ fn add_can_execute_module_test() {
                     .push_arg::<multiversx_sc::types::BigUint<DebugApi>>(managed_biguint!(5));
 
                 let func_result = sc.propose_transfer_execute(
-                    managed_address!(adder_addr),
+                    managed_address!(&adder_addr),
                     managed_biguint!(0),
                     Option::None,
+                    None,
+                    None,
                     FunctionCall {
                         function_name: managed_buffer!(b"add"),
                         arg_buffer: add_function_args,
                     },
-                    OptionalValue::None,
                 );
 
                 // action didn't execute, it returned action_id
@@ -643,3 +805,3406 @@ fn add_can_execute_module_test() {
         )
         .assert_ok();
 }
+
+#[test]
+fn secp256r1_passkey_sign_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+
+    let new_board_member = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let action_id = ms_setup.propose_add_board_member(&new_board_member);
+
+    let signing_key = passkey_signing_key();
+    let signer_addr = ms_setup.second_board_member.clone();
+    ms_setup.register_signing_key(
+        &signer_addr,
+        SignatureType::Secp256r1,
+        &passkey_public_key(&signing_key),
+    );
+
+    let mut challenge = [0u8; 32];
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            let action = sc.action_mapper().get(action_id);
+            challenge = sc
+                .serialize_and_hash_action(&action, &managed_address!(&signer_addr), 0)
+                .to_byte_array();
+        })
+        .assert_ok();
+
+    let vector = webauthn_sign(&signing_key, &challenge);
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let webauthn_sig = WebAuthnSignature::<DebugApi> {
+                    authenticator_data: managed_buffer!(&vector.authenticator_data),
+                    client_data_json: managed_buffer!(&vector.client_data_json),
+                    signature: managed_buffer!(&vector.signature),
+                };
+                let mut raw_sig_bytes = ManagedBuffer::new();
+                let _ = webauthn_sig.top_encode(&mut raw_sig_bytes);
+
+                let mut signatures = MultiValueEncoded::new();
+                signatures.push(SignatureArg {
+                    user_address: managed_address!(&signer_addr),
+                    nonce: 0,
+                    action_type: ActionType::SimpleAction,
+                    signature_type: SignatureType::Secp256r1,
+                    raw_sig_bytes,
+                });
+
+                sc.sign(action_id, signatures);
+            },
+        )
+        .assert_ok();
+
+    ms_setup.perform(action_id);
+    ms_setup.expect_user_role(&new_board_member, UserRole::BoardMember);
+}
+
+#[test]
+fn secp256r1_passkey_tampered_sign_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+
+    let new_board_member = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let action_id = ms_setup.propose_add_board_member(&new_board_member);
+
+    let signing_key = passkey_signing_key();
+    let signer_addr = ms_setup.second_board_member.clone();
+
+    let mut challenge = [0u8; 32];
+    let mut wrong_nonce_challenge = [0u8; 32];
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            let action = sc.action_mapper().get(action_id);
+            challenge = sc
+                .serialize_and_hash_action(&action, &managed_address!(&signer_addr), 0)
+                .to_byte_array();
+            wrong_nonce_challenge = sc
+                .serialize_and_hash_action(&action, &managed_address!(&signer_addr), 1)
+                .to_byte_array();
+        })
+        .assert_ok();
+
+    let valid_vector = webauthn_sign(&signing_key, &challenge);
+
+    let mut tampered_auth_data = webauthn_sign(&signing_key, &challenge);
+    tampered_auth_data.authenticator_data[36] = 2;
+
+    let mut user_not_present = webauthn_sign(&signing_key, &challenge);
+    user_not_present.authenticator_data[32] = 0;
+
+    let other_key_vector = webauthn_sign(&SigningKey::from_slice(&[7u8; 32]).unwrap(), &challenge);
+
+    let test_cases = [
+        (&valid_vector, "No signing key registered"),
+        (
+            &webauthn_sign(&signing_key, &wrong_nonce_challenge),
+            "WebAuthn challenge mismatch",
+        ),
+        (&tampered_auth_data, "Failed checking Secp256r1 signature"),
+        (&user_not_present, "WebAuthn user presence flag not set"),
+        (&other_key_vector, "Failed checking Secp256r1 signature"),
+    ];
+
+    for (i, (vector, err_message)) in test_cases.iter().enumerate() {
+        // key is registered after the first case, which checks the unregistered key error
+        if i == 1 {
+            ms_setup.register_signing_key(
+                &signer_addr,
+                SignatureType::Secp256r1,
+                &passkey_public_key(&signing_key),
+            );
+        }
+
+        ms_setup
+            .b_mock
+            .execute_tx(
+                &ms_setup.first_board_member,
+                &ms_setup.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let webauthn_sig = WebAuthnSignature::<DebugApi> {
+                        authenticator_data: managed_buffer!(&vector.authenticator_data),
+                        client_data_json: managed_buffer!(&vector.client_data_json),
+                        signature: managed_buffer!(&vector.signature),
+                    };
+                    let mut raw_sig_bytes = ManagedBuffer::new();
+                    let _ = webauthn_sig.top_encode(&mut raw_sig_bytes);
+
+                    let mut signatures = MultiValueEncoded::new();
+                    signatures.push(SignatureArg {
+                        user_address: managed_address!(&signer_addr),
+                        nonce: 0,
+                        action_type: ActionType::SimpleAction,
+                        signature_type: SignatureType::Secp256r1,
+                        raw_sig_bytes,
+                    });
+
+                    sc.sign(action_id, signatures);
+                },
+            )
+            .assert_user_error(err_message);
+    }
+
+    ms_setup.perform_and_expect_err(action_id, "quorum has not been reached");
+}
+
+#[test]
+fn signing_key_cleared_on_removal_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+
+    let new_board_member = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let action_id = ms_setup.propose_add_board_member(&new_board_member);
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform(action_id);
+
+    let passkey = passkey_public_key(&passkey_signing_key());
+    ms_setup.register_signing_key(&new_board_member, SignatureType::Secp256r1, &passkey);
+
+    let action_id = ms_setup.propose_remove_user(&new_board_member);
+    ms_setup.sign(action_id, 1);
+    ms_setup.perform(action_id);
+
+    // added back, but the previous passkey is not trusted anymore
+    let action_id = ms_setup.propose_add_board_member(&new_board_member);
+    ms_setup.sign(action_id, 2);
+    ms_setup.perform(action_id);
+    ms_setup.expect_user_role(&new_board_member, UserRole::BoardMember);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            let signing_key = sc.get_signing_key_view(
+                managed_address!(&new_board_member),
+                SignatureType::Secp256r1,
+            );
+            assert!(signing_key.is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn ed25519_invalid_signature_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+
+    let new_board_member = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let action_id = ms_setup.propose_add_board_member(&new_board_member);
+
+    let signer_addr = ms_setup.second_board_member.clone();
+    let first_board_member = ms_setup.first_board_member.clone();
+    let valid_signature = ms_setup.ed25519_action_signature(&signer_addr, action_id, 0);
+
+    let mut tampered_signature = valid_signature.clone();
+    tampered_signature[0] ^= 1;
+
+    let wrong_nonce_signature = ms_setup.ed25519_action_signature(&signer_addr, action_id, 1);
+    let wrong_signer_signature =
+        ms_setup.ed25519_action_signature(&first_board_member, action_id, 0);
+
+    for signature in [
+        tampered_signature,
+        wrong_nonce_signature,
+        wrong_signer_signature,
+    ] {
+        ms_setup
+            .b_mock
+            .execute_tx(
+                &ms_setup.first_board_member,
+                &ms_setup.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let mut signatures = MultiValueEncoded::new();
+                    signatures.push(SignatureArg {
+                        user_address: managed_address!(&signer_addr),
+                        nonce: 0,
+                        action_type: ActionType::SimpleAction,
+                        signature_type: SignatureType::Ed25519,
+                        raw_sig_bytes: managed_buffer!(&signature),
+                    });
+
+                    sc.sign(action_id, signatures);
+                },
+            )
+            .assert_error(10, "invalid signature");
+    }
+
+    ms_setup.perform_and_expect_err(action_id, "quorum has not been reached");
+
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform(action_id);
+    ms_setup.expect_user_role(&new_board_member, UserRole::BoardMember);
+}
+
+#[test]
+fn sign_batch_ed25519_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+
+    let first_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let second_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let mut group_id = 0;
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut actions = MultiValueEncoded::new();
+                actions.push(Action::AddProposer(managed_address!(&first_proposer)));
+                actions.push(Action::AddProposer(managed_address!(&second_proposer)));
+
+                group_id = sc.propose_batch(None, actions).into_option().unwrap();
+            },
+        )
+        .assert_ok();
+
+    // a signature over a single action of the group is not valid for the whole group
+    let signer_addr = ms_setup.second_board_member.clone();
+    let first_action_id = 1;
+    let invalid_signature = ms_setup.ed25519_action_signature(&signer_addr, first_action_id, 0);
+    let valid_signature = ms_setup.ed25519_group_signature(&signer_addr, group_id, 0);
+
+    for (signature, expected_ok) in [(invalid_signature, false), (valid_signature, true)] {
+        let tx_result = ms_setup.b_mock.execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut signatures = MultiValueEncoded::new();
+                signatures.push(SignatureArg {
+                    user_address: managed_address!(&signer_addr),
+                    nonce: 0,
+                    action_type: ActionType::Group,
+                    signature_type: SignatureType::Ed25519,
+                    raw_sig_bytes: managed_buffer!(&signature),
+                });
+
+                sc.sign_batch(group_id, signatures);
+            },
+        );
+        if expected_ok {
+            tx_result.assert_ok();
+        } else {
+            tx_result.assert_error(10, "invalid signature");
+        }
+    }
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.perform_batch(group_id);
+            },
+        )
+        .assert_ok();
+
+    ms_setup.expect_user_role(&first_proposer, UserRole::Proposer);
+    ms_setup.expect_user_role(&second_proposer, UserRole::Proposer);
+}
+
+#[test]
+fn secp256k1_sign_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+
+    let new_board_member = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let action_id = ms_setup.propose_add_board_member(&new_board_member);
+
+    let signing_key = secp256k1_signing_key();
+    let public_key = signing_key
+        .verifying_key()
+        .to_encoded_point(true)
+        .as_bytes()
+        .to_vec();
+    let signer_addr = ms_setup.second_board_member.clone();
+    let hash = ms_setup.action_hash(&signer_addr, action_id, 0);
+    let wrong_nonce_hash = ms_setup.action_hash(&signer_addr, action_id, 1);
+
+    let other_key = k256::ecdsa::SigningKey::from_slice(&[7u8; 32]).unwrap();
+    let test_cases = [
+        (
+            secp256k1_sign(&signing_key, &hash),
+            "Failed checking Secp256k1 signature",
+        ),
+        (
+            secp256k1_sign(&signing_key, &wrong_nonce_hash),
+            "Failed checking Secp256k1 signature",
+        ),
+        (
+            secp256k1_sign(&other_key, &hash),
+            "Failed checking Secp256k1 signature",
+        ),
+        (b"signature".to_vec(), "Failed checking Secp256k1 signature"),
+    ];
+
+    for (i, (signature, err_message)) in test_cases.iter().enumerate() {
+        // the address is used as key until the signer registers one
+        if i == 1 {
+            ms_setup.register_signing_key(&signer_addr, SignatureType::Secp256k1, &public_key);
+        }
+
+        ms_setup
+            .b_mock
+            .execute_tx(
+                &ms_setup.first_board_member,
+                &ms_setup.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let mut signatures = MultiValueEncoded::new();
+                    signatures.push(SignatureArg {
+                        user_address: managed_address!(&signer_addr),
+                        nonce: 0,
+                        action_type: ActionType::SimpleAction,
+                        signature_type: SignatureType::Secp256k1,
+                        raw_sig_bytes: managed_buffer!(signature),
+                    });
+
+                    sc.sign(action_id, signatures);
+                },
+            )
+            .assert_user_error(err_message);
+    }
+
+    let signature = secp256k1_sign(&signing_key, &hash);
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut signatures = MultiValueEncoded::new();
+                signatures.push(SignatureArg {
+                    user_address: managed_address!(&signer_addr),
+                    nonce: 0,
+                    action_type: ActionType::SimpleAction,
+                    signature_type: SignatureType::Secp256k1,
+                    raw_sig_bytes: managed_buffer!(&signature),
+                });
+
+                sc.sign(action_id, signatures);
+            },
+        )
+        .assert_ok();
+
+    ms_setup.perform(action_id);
+    ms_setup.expect_user_role(&new_board_member, UserRole::BoardMember);
+}
+
+#[test]
+fn transfer_execute_batch_other_shard_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+
+    // "sc:" addresses end with '_', which is in shard 2
+    let mut other_shard_address = sc_address(b"adder").to_vec();
+    other_shard_address[31] = 0;
+    let other_shard_address = Address::from_slice(&other_shard_address);
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut actions = MultiValueEncoded::new();
+                actions.push(Action::SendTransferExecuteEgld(CallActionData {
+                    to: managed_address!(&other_shard_address),
+                    egld_amount: managed_biguint!(0),
+                    opt_gas_limit: None,
+                    endpoint_name: managed_buffer!(b"add"),
+                    arguments: ManagedVec::new(),
+                }));
+
+                let _ = sc.propose_batch(None, actions);
+            },
+        )
+        .assert_user_error("All transfer exec must be to the same shard");
+}
+
+#[test]
+fn action_deadline_timestamp_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    ms_setup.b_mock.set_block_timestamp(100);
+
+    let new_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let action_id =
+        ms_setup.propose_add_proposer_with_deadline(&new_proposer, Deadline::Timestamp(200));
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            let pending_actions: Vec<_> = sc
+                .get_pending_action_full_info(OptionalValue::None)
+                .into_iter()
+                .collect();
+            assert_eq!(pending_actions.len(), 1);
+            assert_eq!(pending_actions[0].deadline, Some(Deadline::Timestamp(200)));
+        })
+        .assert_ok();
+
+    // deadline is inclusive
+    ms_setup.b_mock.set_block_timestamp(200);
+    ms_setup.sign(action_id, 0);
+
+    ms_setup.b_mock.set_block_timestamp(201);
+    ms_setup.perform_and_expect_err(action_id, "action expired");
+
+    let signer_addr = ms_setup.second_board_member.clone();
+    let signature = ms_setup.ed25519_action_signature(&signer_addr, action_id, 1);
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.second_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut signatures = MultiValueEncoded::new();
+                signatures.push(SignatureArg {
+                    user_address: managed_address!(&signer_addr),
+                    nonce: 1,
+                    action_type: ActionType::SimpleAction,
+                    signature_type: SignatureType::Ed25519,
+                    raw_sig_bytes: managed_buffer!(&signature),
+                });
+
+                sc.sign(action_id, signatures);
+            },
+        )
+        .assert_user_error("action expired");
+
+    // anyone can clean up expired actions
+    let random_user = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &random_user,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut action_ids = MultiValueEncoded::new();
+                action_ids.push(action_id);
+
+                sc.discard_expired_actions(action_ids);
+            },
+        )
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert!(sc.get_action_data(action_id).is_nothing());
+            assert_eq!(sc.action_deadline(action_id).get(), None);
+        })
+        .assert_ok();
+    ms_setup.expect_user_role(&new_proposer, UserRole::None);
+}
+
+#[test]
+fn signed_proposal_deadline_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    ms_setup.b_mock.set_block_timestamp(100);
+
+    let new_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let signer_addr = ms_setup.second_board_member.clone();
+    let signed_deadline = Some(Deadline::Timestamp(200));
+    let signature = ms_setup.ed25519_add_proposer_proposal_signature(
+        &signer_addr,
+        &new_proposer,
+        signed_deadline,
+        0,
+    );
+
+    // the relayer cannot change or drop the deadline the proposer signed
+    for (opt_deadline, expected_ok) in [
+        (Some(Deadline::Timestamp(300)), false),
+        (None, false),
+        (signed_deadline, true),
+    ] {
+        let tx_result = ms_setup.b_mock.execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.propose_add_proposer(
+                    managed_address!(&new_proposer),
+                    opt_deadline,
+                    OptionalValue::Some(SignatureArg {
+                        user_address: managed_address!(&signer_addr),
+                        nonce: 0,
+                        action_type: ActionType::Propose,
+                        signature_type: SignatureType::Ed25519,
+                        raw_sig_bytes: managed_buffer!(&signature),
+                    }),
+                );
+            },
+        );
+        if expected_ok {
+            tx_result.assert_ok();
+        } else {
+            tx_result.assert_error(10, "invalid signature");
+        }
+    }
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.action_deadline(1).get(), signed_deadline);
+            assert_eq!(sc.get_action_signer_count(1), 1);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn action_deadline_round_batch_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    ms_setup.b_mock.set_block_round(5);
+
+    // deadline must be in the future
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.propose_change_quorum(1, Some(Deadline::Round(4)), OptionalValue::None);
+            },
+        )
+        .assert_user_error("deadline already passed");
+
+    let first_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let second_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let mut group_id = 0;
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut actions = MultiValueEncoded::new();
+                actions.push(Action::AddProposer(managed_address!(&first_proposer)));
+                actions.push(Action::AddProposer(managed_address!(&second_proposer)));
+
+                group_id = sc
+                    .propose_batch(Some(Deadline::Round(10)), actions)
+                    .into_option()
+                    .unwrap();
+            },
+        )
+        .assert_ok();
+
+    // actions that did not expire cannot be discarded without unsigning first
+    let random_user = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &random_user,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut action_ids = MultiValueEncoded::new();
+                action_ids.push(1);
+
+                sc.discard_expired_actions(action_ids);
+            },
+        )
+        .assert_user_error("action not expired");
+
+    ms_setup.b_mock.set_block_round(11);
+
+    let signer_addr = ms_setup.second_board_member.clone();
+    let signature = ms_setup.ed25519_group_signature(&signer_addr, group_id, 0);
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.second_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut signatures = MultiValueEncoded::new();
+                signatures.push(SignatureArg {
+                    user_address: managed_address!(&signer_addr),
+                    nonce: 0,
+                    action_type: ActionType::Group,
+                    signature_type: SignatureType::Ed25519,
+                    raw_sig_bytes: managed_buffer!(&signature),
+                });
+
+                sc.sign_batch(group_id, signatures);
+            },
+        )
+        .assert_user_error("action expired");
+
+    // discarding one expired action aborts the whole batch
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &random_user,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut action_ids = MultiValueEncoded::new();
+                action_ids.push(1);
+
+                sc.discard_expired_actions(action_ids);
+            },
+        )
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.perform_batch(group_id);
+            },
+        )
+        .assert_user_error("cannot perform actions of an aborted batch");
+}
+
+#[test]
+fn execution_delay_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    ms_setup.b_mock.set_block_timestamp(100);
+
+    // no delay by default
+    let action_id = ms_setup.propose_set_execution_delay(60);
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform(action_id);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.execution_delay().get(), 60);
+        })
+        .assert_ok();
+
+    let new_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let action_id = ms_setup.propose_add_proposer(&new_proposer);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.get_earliest_execution_time_view(action_id), None);
+        })
+        .assert_ok();
+
+    // the delay is counted from the moment quorum was reached
+    ms_setup.b_mock.set_block_timestamp(110);
+    ms_setup.sign(action_id, 1);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.get_earliest_execution_time_view(action_id), Some(170));
+        })
+        .assert_ok();
+
+    ms_setup.b_mock.set_block_timestamp(169);
+    ms_setup.perform_and_expect_err(action_id, "execution delay has not passed");
+
+    ms_setup.b_mock.set_block_timestamp(170);
+    ms_setup.perform(action_id);
+    ms_setup.expect_user_role(&new_proposer, UserRole::Proposer);
+
+    // delays above the maximum are rejected
+    let action_id = ms_setup.propose_set_execution_delay(31 * 24 * 60 * 60);
+    ms_setup.sign(action_id, 2);
+    ms_setup.b_mock.set_block_timestamp(230);
+    ms_setup.perform_and_expect_err(action_id, "execution delay too big");
+}
+
+#[test]
+fn execution_delay_restarts_when_quorum_regained_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    ms_setup.b_mock.set_block_timestamp(100);
+
+    let mut action_id = 0;
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                action_id = sc.propose_set_action_kind_execution_delay(
+                    ActionKind::AddProposer,
+                    Some(100),
+                    None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform(action_id);
+
+    let new_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let add_proposer_action_id = ms_setup.propose_add_proposer(&new_proposer);
+    ms_setup.sign(add_proposer_action_id, 1);
+
+    // the second member leaves, so the action loses quorum
+    let action_id = ms_setup.propose_change_quorum(1);
+    ms_setup.sign(action_id, 2);
+    ms_setup.perform(action_id);
+
+    let second_board_member = ms_setup.second_board_member.clone();
+    let action_id = ms_setup.propose_remove_user(&second_board_member);
+    ms_setup.perform(action_id);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(
+                sc.get_earliest_execution_time_view(add_proposer_action_id),
+                None
+            );
+        })
+        .assert_ok();
+
+    // once added back, the old signature counts again, but the delay starts over
+    ms_setup.b_mock.set_block_timestamp(150);
+    let action_id = ms_setup.propose_add_board_member(&second_board_member);
+    ms_setup.perform(action_id);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(
+                sc.get_earliest_execution_time_view(add_proposer_action_id),
+                Some(250)
+            );
+        })
+        .assert_ok();
+
+    ms_setup.b_mock.set_block_timestamp(200);
+    ms_setup.perform_and_expect_err(add_proposer_action_id, "execution delay has not passed");
+
+    ms_setup.b_mock.set_block_timestamp(250);
+    ms_setup.perform(add_proposer_action_id);
+    ms_setup.expect_user_role(&new_proposer, UserRole::Proposer);
+
+    // actions that had quorum before the timestamps were recorded count from the upgrade
+    ms_setup.b_mock.set_block_timestamp(300);
+    let other_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let action_id = ms_setup.propose_add_proposer(&other_proposer);
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.quorum_reached_timestamp(action_id).clear();
+                sc.quorum_tracking_start_timestamp().set(320);
+            },
+        )
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.get_earliest_execution_time_view(action_id), Some(420));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn execution_delay_action_kind_and_batch_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    ms_setup.b_mock.set_block_timestamp(100);
+
+    let action_id = ms_setup.propose_set_execution_delay(60);
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform(action_id);
+
+    // override the delay for adding proposers
+    let mut action_id = 0;
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                action_id = sc.propose_set_action_kind_execution_delay(
+                    ActionKind::AddProposer,
+                    Some(10),
+                    None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+    ms_setup.sign(action_id, 1);
+
+    // the governance action itself is subject to the default delay
+    ms_setup.perform_and_expect_err(action_id, "execution delay has not passed");
+    ms_setup.b_mock.set_block_timestamp(160);
+    ms_setup.perform(action_id);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(
+                sc.action_kind_execution_delay(ActionKind::AddProposer)
+                    .get(),
+                Some(10)
+            );
+        })
+        .assert_ok();
+
+    // the batch timing starts once all its actions reached quorum
+    let first_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let mut group_id = 0;
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut actions = MultiValueEncoded::new();
+                actions.push(Action::AddProposer(managed_address!(&first_proposer)));
+                actions.push(Action::ChangeQuorum(1));
+
+                group_id = sc.propose_batch(None, actions).into_option().unwrap();
+            },
+        )
+        .assert_ok();
+
+    let signer_addr = ms_setup.second_board_member.clone();
+    let signature = ms_setup.ed25519_group_signature(&signer_addr, group_id, 2);
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.second_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut signatures = MultiValueEncoded::new();
+                signatures.push(SignatureArg {
+                    user_address: managed_address!(&signer_addr),
+                    nonce: 2,
+                    action_type: ActionType::Group,
+                    signature_type: SignatureType::Ed25519,
+                    raw_sig_bytes: managed_buffer!(&signature),
+                });
+
+                sc.sign_batch(group_id, signatures);
+            },
+        )
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.get_earliest_execution_time_view(3), Some(170));
+            assert_eq!(sc.get_earliest_execution_time_view(4), Some(220));
+            assert_eq!(sc.get_group_earliest_execution_time(group_id), Some(220));
+        })
+        .assert_ok();
+
+    ms_setup.b_mock.set_block_timestamp(219);
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.perform_batch(group_id);
+            },
+        )
+        .assert_user_error("execution delay has not passed");
+
+    ms_setup.b_mock.set_block_timestamp(220);
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.perform_batch(group_id);
+            },
+        )
+        .assert_ok();
+    ms_setup.expect_user_role(&first_proposer, UserRole::Proposer);
+
+    // removing the override falls back to the default delay
+    let mut action_id = 0;
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                action_id = sc.propose_set_action_kind_execution_delay(
+                    ActionKind::AddProposer,
+                    None,
+                    None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_ok();
+
+    ms_setup.b_mock.set_block_timestamp(280);
+    ms_setup.perform(action_id);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(
+                sc.action_kind_execution_delay(ActionKind::AddProposer)
+                    .get(),
+                None
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn board_member_weight_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    let first_board_member = ms_setup.first_board_member.clone();
+    let second_board_member = ms_setup.second_board_member.clone();
+
+    let action_id = ms_setup.propose_set_board_member_weight(&first_board_member, 3);
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform(action_id);
+
+    let action_id = ms_setup.propose_change_quorum(3);
+    ms_setup.sign(action_id, 1);
+    ms_setup.perform(action_id);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(
+                sc.get_board_member_weight(managed_address!(&first_board_member)),
+                3
+            );
+            assert_eq!(
+                sc.get_board_member_weight(managed_address!(&second_board_member)),
+                1
+            );
+            assert_eq!(sc.get_total_board_weight_view(), 4);
+
+            let board_members: Vec<_> = sc
+                .get_all_board_members_with_weight()
+                .into_iter()
+                .map(|member| member.into_tuple())
+                .collect();
+            assert_eq!(
+                board_members,
+                vec![
+                    (managed_address!(&first_board_member), 3),
+                    (managed_address!(&second_board_member), 1)
+                ]
+            );
+        })
+        .assert_ok();
+
+    // the first board member reaches quorum alone
+    let new_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let action_id = ms_setup.propose_add_proposer(&new_proposer);
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.get_action_valid_signer_count_view(action_id), 1);
+            assert_eq!(sc.get_action_valid_signer_weight_view(action_id), 3);
+            assert!(sc.quorum_reached(action_id));
+        })
+        .assert_ok();
+    ms_setup.perform(action_id);
+    ms_setup.expect_user_role(&new_proposer, UserRole::Proposer);
+
+    // only board members have a weight
+    let action_id = ms_setup.propose_set_board_member_weight(&new_proposer, 2);
+    ms_setup.perform_and_expect_err(action_id, "only board members have voting weight");
+
+    // the total weight cannot drop below the quorum
+    let action_id = ms_setup.propose_set_board_member_weight(&first_board_member, 1);
+    ms_setup.perform_and_expect_err(action_id, "quorum cannot exceed total board weight");
+
+    // the second board member alone does not reach quorum
+    let mut action_id = 0;
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &second_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                action_id = sc.propose_change_quorum(1, None, OptionalValue::None);
+            },
+        )
+        .assert_ok();
+    ms_setup.perform_and_expect_err(action_id, "quorum has not been reached");
+
+    // members joining or leaving the board update the total weight
+    let third_board_member = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let action_id = ms_setup.propose_add_board_member(&third_board_member);
+    ms_setup.perform(action_id);
+
+    let action_id = ms_setup.propose_set_board_member_weight(&third_board_member, 2);
+    ms_setup.perform(action_id);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.get_total_board_weight_view(), 6);
+        })
+        .assert_ok();
+
+    let action_id = ms_setup.propose_remove_user(&first_board_member);
+    ms_setup.perform(action_id);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.get_total_board_weight_view(), 3);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn action_kind_quorum_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    let third_board_member = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    let action_id = ms_setup.propose_add_board_member(&third_board_member);
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform(action_id);
+
+    // overrides cannot exceed the board size either
+    let action_id = ms_setup.propose_change_action_kind_quorum(ActionKind::AddBoardMember, Some(4));
+    ms_setup.sign(action_id, 1);
+    ms_setup.perform_and_expect_err(action_id, "quorum cannot exceed total board weight");
+
+    let action_id = ms_setup.propose_change_action_kind_quorum(ActionKind::AddBoardMember, Some(3));
+    ms_setup.sign(action_id, 2);
+    ms_setup.perform(action_id);
+
+    let action_id = ms_setup.propose_change_action_kind_quorum(ActionKind::AddProposer, Some(1));
+    ms_setup.sign(action_id, 3);
+    ms_setup.perform(action_id);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.get_action_kind_quorum(ActionKind::AddBoardMember), 3);
+            assert_eq!(sc.get_action_kind_quorum(ActionKind::AddProposer), 1);
+            assert_eq!(sc.get_action_kind_quorum(ActionKind::RemoveUser), 2);
+        })
+        .assert_ok();
+
+    // the proposer alone is enough to add a proposer
+    let new_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let action_id = ms_setup.propose_add_proposer(&new_proposer);
+    ms_setup.perform(action_id);
+    ms_setup.expect_user_role(&new_proposer, UserRole::Proposer);
+
+    // two signatures are no longer enough to add a board member
+    let new_board_member = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let action_id = ms_setup.propose_add_board_member(&new_board_member);
+    ms_setup.sign(action_id, 4);
+    ms_setup.perform_and_expect_err(action_id, "quorum has not been reached");
+
+    // the board cannot shrink below any of the overrides
+    let action_id = ms_setup.propose_remove_user(&third_board_member);
+    ms_setup.sign(action_id, 5);
+    ms_setup.perform_and_expect_err(action_id, "quorum cannot exceed total board weight");
+
+    // each action in a batch requires the quorum of its own type
+    let other_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let mut group_id = 0;
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut actions = MultiValueEncoded::new();
+                actions.push(Action::AddProposer(managed_address!(&other_proposer)));
+                actions.push(Action::ChangeQuorum(1));
+
+                group_id = sc.propose_batch(None, actions).into_option().unwrap();
+            },
+        )
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.perform_batch(group_id);
+            },
+        )
+        .assert_user_error("quorum has not been reached");
+
+    // removing the override falls back to the default quorum
+    let action_id = ms_setup.propose_change_action_kind_quorum(ActionKind::AddBoardMember, None);
+    ms_setup.sign(action_id, 6);
+    ms_setup.perform(action_id);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.get_action_kind_quorum(ActionKind::AddBoardMember), 2);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn module_notification_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    let rejecting_module = ms_setup.b_mock.create_sc_account_fixed_address(
+        &sc_address(b"rejecting-module"),
+        &rust_biguint!(0),
+        Some(&ms_setup.ms_owner),
+        NotifiedModuleMock::rejecting,
+        "rejecting module mock",
+    );
+    let approving_module = ms_setup.b_mock.create_sc_account_fixed_address(
+        &sc_address(b"approving-module"),
+        &rust_biguint!(0),
+        Some(&ms_setup.ms_owner),
+        NotifiedModuleMock::approving,
+        "approving module mock",
+    );
+
+    let mut signer_nonce = 0;
+    for module_address in [
+        rejecting_module.address_ref(),
+        approving_module.address_ref(),
+    ] {
+        let action_id = ms_setup.propose_add_module(module_address);
+        ms_setup.sign(action_id, signer_nonce);
+        ms_setup.perform(action_id);
+
+        let action_id = ms_setup.propose_set_module_notifications(module_address, true);
+        ms_setup.sign(action_id, signer_nonce + 1);
+        ms_setup.perform(action_id);
+
+        signer_nonce += 2;
+    }
+
+    // only the module that approved the action is notified
+    let adder_address = ms_setup.adder_wrapper.address_ref().clone();
+    ms_setup.propose_transfer_execute_directly(&adder_address, b"add", vec![&[5u8]]);
+    assert_eq!(NotifiedModuleMock::notified_action_ids(), vec![5]);
+
+    // removing the module also disables its notifications
+    let action_id = ms_setup.propose_remove_module(approving_module.address_ref());
+    ms_setup.sign(action_id, signer_nonce);
+    ms_setup.perform(action_id);
+    signer_nonce += 1;
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert!(!sc.get_module_notifications_enabled(managed_address!(
+                approving_module.address_ref()
+            )));
+        })
+        .assert_ok();
+
+    // modules without notifications don't need to implement the endpoint
+    let can_execute_mock = ms_setup.b_mock.create_sc_account_fixed_address(
+        &sc_address(b"can-execute-mock"),
+        &rust_biguint!(0),
+        Some(&ms_setup.ms_owner),
+        CanExecuteMock::new,
+        "canExecute mock",
+    );
+    let action_id = ms_setup.propose_add_module(can_execute_mock.address_ref());
+    ms_setup.sign(action_id, signer_nonce);
+    ms_setup.perform(action_id);
+
+    ms_setup.propose_transfer_execute_directly(&adder_address, b"add", vec![&[5u8]]);
+    assert_eq!(NotifiedModuleMock::notified_action_ids(), vec![5]);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.adder_wrapper, |sc| {
+            assert_eq!(sc.sum().get(), 10);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn recent_module_executions_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    let can_execute_mock = ms_setup.b_mock.create_sc_account_fixed_address(
+        &sc_address(b"can-execute-mock"),
+        &rust_biguint!(0),
+        Some(&ms_setup.ms_owner),
+        CanExecuteMock::new,
+        "canExecute mock",
+    );
+
+    let action_id = ms_setup.propose_add_module(can_execute_mock.address_ref());
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform(action_id);
+
+    ms_setup.b_mock.set_block_timestamp(100);
+    let adder_address = ms_setup.adder_wrapper.address_ref().clone();
+    ms_setup.propose_transfer_execute_directly(&adder_address, b"add", vec![&[5u8]]);
+
+    let first_board_member = ms_setup.first_board_member.clone();
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            let executions: Vec<_> = sc.get_recent_module_executions().into_iter().collect();
+            assert_eq!(executions.len(), 1);
+
+            let execution = &executions[0];
+            assert_eq!(execution.action_id, 2);
+            assert_eq!(
+                execution.module_address,
+                managed_address!(can_execute_mock.address_ref())
+            );
+            assert_eq!(execution.proposer, managed_address!(&first_board_member));
+            assert_eq!(execution.timestamp, 100);
+        })
+        .assert_ok();
+
+    // only the most recent executions are kept
+    for _ in 0..MAX_RECENT_MODULE_EXECUTIONS {
+        ms_setup.propose_transfer_execute_directly(&adder_address, b"add", vec![&[1u8]]);
+    }
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            let executions: Vec<_> = sc.get_recent_module_executions().into_iter().collect();
+            assert_eq!(executions.len(), MAX_RECENT_MODULE_EXECUTIONS);
+            assert_eq!(executions[0].action_id, 3);
+            assert_eq!(
+                executions[MAX_RECENT_MODULE_EXECUTIONS - 1].action_id,
+                MAX_RECENT_MODULE_EXECUTIONS + 2
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn module_action_approval_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    let can_execute_mock = ms_setup.b_mock.create_sc_account_fixed_address(
+        &sc_address(b"can-execute-mock"),
+        &rust_biguint!(0),
+        Some(&ms_setup.ms_owner),
+        CanExecuteMock::new,
+        "canExecute mock",
+    );
+    let action_approval_mock = ms_setup.b_mock.create_sc_account_fixed_address(
+        &sc_address(b"action-approval-mock"),
+        &rust_biguint!(0),
+        Some(&ms_setup.ms_owner),
+        ActionApprovalMock::new,
+        "canExecuteAction mock",
+    );
+
+    let action_id = ms_setup.propose_add_module(can_execute_mock.address_ref());
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform(action_id);
+
+    // modules using the original interface can only approve transfer-execute actions
+    let adder_address = ms_setup.adder_wrapper.address_ref().clone();
+    let pending_action_id =
+        ms_setup.propose_async_call(&adder_address, 0, b"add", vec![&[5u8][..]]);
+
+    let action_id = ms_setup.propose_add_module(action_approval_mock.address_ref());
+    ms_setup.sign(action_id, 1);
+    ms_setup.perform(action_id);
+
+    let action_id =
+        ms_setup.propose_set_module_action_approval(action_approval_mock.address_ref(), true);
+    ms_setup.sign(action_id, 2);
+    ms_setup.perform(action_id);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert!(sc.get_module_action_approval_enabled(managed_address!(
+                action_approval_mock.address_ref()
+            )));
+            assert!(!sc.get_module_action_approval_enabled(managed_address!(
+                can_execute_mock.address_ref()
+            )));
+        })
+        .assert_ok();
+
+    // async call approved through canExecuteAction, the call ends the execution right away
+    ms_setup.b_mock.set_block_timestamp(500);
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.propose_async_call(
+                    managed_address!(&adder_address),
+                    managed_biguint!(0),
+                    None,
+                    None,
+                    None,
+                    FunctionCall::new("add").argument(&5u32),
+                );
+            },
+        )
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.adder_wrapper, |sc| {
+            assert_eq!(sc.sum().get(), 5);
+        })
+        .assert_ok();
+
+    // proposing through a module counts as activity
+    let first_board_member = ms_setup.first_board_member.clone();
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            let activity = sc.get_member_activity(managed_address!(&first_board_member));
+            assert_eq!(activity.map(|activity| activity.timestamp), Some(500));
+        })
+        .assert_ok();
+
+    // deploys performed directly have no action ID to return
+    let new_adder_wrapper = ms_setup.prepare_deploy_from_ms(adder::contract_obj);
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut args = MultiValueEncoded::new();
+                args.push(managed_buffer!(&[7u8]));
+
+                let result = sc.propose_sc_deploy_from_source(
+                    managed_biguint!(0),
+                    managed_address!(&adder_address),
+                    CodeMetadata::all(),
+                    None,
+                    None,
+                    args,
+                );
+                assert!(result.is_none());
+            },
+        )
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_query(&new_adder_wrapper, |sc| {
+            assert_eq!(sc.sum().get(), 7);
+        })
+        .assert_ok();
+
+    // batches are executed directly only if all actions are approved
+    let new_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let transfer_action = Action::SendTransferExecuteEgld(CallActionData {
+                    to: managed_address!(&adder_address),
+                    egld_amount: managed_biguint!(0),
+                    opt_gas_limit: None,
+                    endpoint_name: managed_buffer!(b"add"),
+                    arguments: ManagedVec::from_single_item(managed_buffer!(&[5u8])),
+                });
+
+                let mut actions = MultiValueEncoded::new();
+                actions.push(transfer_action.clone());
+                actions.push(transfer_action.clone());
+                assert!(sc.propose_batch(None, actions).is_none());
+
+                let mut actions = MultiValueEncoded::new();
+                actions.push(transfer_action);
+                actions.push(Action::AddProposer(managed_address!(&new_proposer)));
+                assert!(sc.propose_batch(None, actions).is_some());
+            },
+        )
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.adder_wrapper, |sc| {
+            assert_eq!(sc.sum().get(), 15);
+        })
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert!(sc.get_action_data(pending_action_id).is_pending());
+        })
+        .assert_ok();
+    ms_setup.expect_user_role(&new_proposer, UserRole::None);
+}
+
+#[test]
+fn guardian_veto_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+
+    let guardian = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let action_id = ms_setup.propose_add_guardian(&guardian);
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform(action_id);
+    ms_setup.expect_user_role(&guardian, UserRole::Guardian);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.num_guardians().get(), 1);
+            assert_eq!(sc.num_proposers().get(), 0);
+        })
+        .assert_ok();
+
+    // guardians can't propose
+    let new_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    ms_setup
+        .b_mock
+        .execute_tx(&guardian, &ms_setup.ms_wrapper, &rust_biguint!(0), |sc| {
+            let _ =
+                sc.propose_add_proposer(managed_address!(&new_proposer), None, OptionalValue::None);
+        })
+        .assert_user_error("only board members and proposers can propose");
+
+    // veto an action that already reached quorum
+    let action_id = ms_setup.propose_add_proposer(&new_proposer);
+    ms_setup.sign(action_id, 1);
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.veto_action_endpoint(action_id);
+            },
+        )
+        .assert_user_error("only guardians can veto actions");
+
+    ms_setup
+        .b_mock
+        .execute_tx(&guardian, &ms_setup.ms_wrapper, &rust_biguint!(0), |sc| {
+            sc.veto_action_endpoint(action_id);
+        })
+        .assert_ok();
+
+    ms_setup.perform_and_expect_err(action_id, "quorum has not been reached");
+    ms_setup.expect_user_role(&new_proposer, UserRole::None);
+
+    // vetoing an action of a batch aborts the whole batch
+    let mut group_id = 0;
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut actions = MultiValueEncoded::new();
+                actions.push(Action::AddProposer(managed_address!(&new_proposer)));
+                actions.push(Action::ChangeQuorum(1));
+
+                group_id = sc.propose_batch(None, actions).into_option().unwrap();
+            },
+        )
+        .assert_ok();
+
+    let vetoed_action_id = action_id + 1;
+    ms_setup
+        .b_mock
+        .execute_tx(&guardian, &ms_setup.ms_wrapper, &rust_biguint!(0), |sc| {
+            sc.veto_action_endpoint(vetoed_action_id);
+        })
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.perform_batch(group_id);
+            },
+        )
+        .assert_user_error("cannot perform actions of an aborted batch");
+
+    ms_setup
+        .b_mock
+        .execute_tx(&guardian, &ms_setup.ms_wrapper, &rust_biguint!(0), |sc| {
+            sc.veto_action_endpoint(vetoed_action_id);
+        })
+        .assert_user_error("action does not exist");
+
+    let action_id = ms_setup.propose_remove_user(&guardian);
+    ms_setup.sign(action_id, 2);
+    ms_setup.perform(action_id);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.num_guardians().get(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn executor_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+
+    let executor = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let action_id = ms_setup.propose_add_executor(&executor);
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform(action_id);
+    ms_setup.expect_user_role(&executor, UserRole::Executor);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            let mut expected_executors = MultiValueEncoded::new();
+            expected_executors.push(managed_address!(&executor));
+
+            assert_eq!(sc.get_all_executors(), expected_executors);
+        })
+        .assert_ok();
+
+    // executors can't propose
+    let new_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    ms_setup
+        .b_mock
+        .execute_tx(&executor, &ms_setup.ms_wrapper, &rust_biguint!(0), |sc| {
+            let _ =
+                sc.propose_add_proposer(managed_address!(&new_proposer), None, OptionalValue::None);
+        })
+        .assert_user_error("only board members and proposers can propose");
+
+    // executors can only perform actions that reached quorum
+    let action_id = ms_setup.propose_add_proposer(&new_proposer);
+    ms_setup
+        .b_mock
+        .execute_tx(&executor, &ms_setup.ms_wrapper, &rust_biguint!(0), |sc| {
+            let _ = sc.perform_action_endpoint(action_id);
+        })
+        .assert_user_error("quorum has not been reached");
+
+    ms_setup.sign(action_id, 1);
+    ms_setup
+        .b_mock
+        .execute_tx(&executor, &ms_setup.ms_wrapper, &rust_biguint!(0), |sc| {
+            let _ = sc.perform_action_endpoint(action_id);
+        })
+        .assert_ok();
+
+    ms_setup.expect_user_role(&new_proposer, UserRole::Proposer);
+}
+
+#[test]
+fn pause_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    let can_execute_mock = ms_setup.b_mock.create_sc_account_fixed_address(
+        &sc_address(b"can-execute-mock"),
+        &rust_biguint!(0),
+        Some(&ms_setup.ms_owner),
+        CanExecuteMock::new,
+        "canExecute mock",
+    );
+
+    let action_id = ms_setup.propose_add_module(can_execute_mock.address_ref());
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform(action_id);
+
+    let new_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let pending_action_id = ms_setup.propose_add_proposer(&new_proposer);
+    ms_setup.sign(pending_action_id, 1);
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &new_proposer,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.pause();
+            },
+        )
+        .assert_user_error("only board members and guardians can pause");
+
+    // a single board member can pause
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.pause();
+            },
+        )
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.second_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                assert!(sc.paused().get());
+                sc.pause();
+            },
+        )
+        .assert_user_error("already paused");
+
+    ms_setup.perform_and_expect_err(pending_action_id, "contract is paused");
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.propose_change_quorum(1, None, OptionalValue::None);
+            },
+        )
+        .assert_user_error("contract is paused");
+
+    // modules can't bypass the pause either
+    let adder_address = ms_setup.adder_wrapper.address_ref().clone();
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.propose_transfer_execute(
+                    managed_address!(&adder_address),
+                    managed_biguint!(0),
+                    None,
+                    None,
+                    None,
+                    FunctionCall::new("add").argument(&5u32),
+                );
+            },
+        )
+        .assert_user_error("contract is paused");
+
+    let unpause_action_id = ms_setup.propose_unpause();
+    ms_setup.sign(unpause_action_id, 2);
+
+    // the board can still be rotated, but unpausing needs the whole board at the time it is performed
+    let new_board_member = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let action_id = ms_setup.propose_add_board_member(&new_board_member);
+    ms_setup.sign(action_id, 3);
+    ms_setup.perform(action_id);
+    ms_setup.expect_user_role(&new_board_member, UserRole::BoardMember);
+
+    ms_setup.perform_and_expect_err(unpause_action_id, "unpause requires the whole board");
+
+    let action_id = ms_setup.propose_remove_user(&new_board_member);
+    ms_setup.sign(action_id, 4);
+    ms_setup.perform(action_id);
+
+    ms_setup.perform(unpause_action_id);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert!(!sc.paused().get());
+        })
+        .assert_ok();
+
+    ms_setup.perform(pending_action_id);
+    ms_setup.expect_user_role(&new_proposer, UserRole::Proposer);
+
+    // the requirement cannot be lowered
+    let action_id = ms_setup.propose_change_action_kind_quorum(ActionKind::Unpause, Some(1));
+    ms_setup.sign(action_id, 5);
+    ms_setup.perform_and_expect_err(action_id, "unpause always requires the whole board");
+}
+
+#[test]
+fn social_recovery_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    let recovery_address = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let new_board_member = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let challenge_period = MIN_RECOVERY_CHALLENGE_PERIOD;
+
+    ms_setup.b_mock.set_block_timestamp(100);
+
+    let action_id = ms_setup
+        .propose_set_recovery_config(challenge_period, std::slice::from_ref(&recovery_address));
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform(action_id);
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut new_board = MultiValueEncoded::new();
+                new_board.push(managed_address!(&new_board_member));
+
+                sc.start_recovery(1, new_board);
+            },
+        )
+        .assert_user_error("only recovery addresses can recover the board");
+
+    // board activity in the same block as the start cancels the recovery
+    ms_setup.b_mock.set_block_timestamp(200);
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &recovery_address,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut new_board = MultiValueEncoded::new();
+                new_board.push(managed_address!(&new_board_member));
+
+                sc.start_recovery(1, new_board);
+            },
+        )
+        .assert_ok();
+    let other_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let _ = ms_setup.propose_add_proposer(&other_proposer);
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.last_board_activity().get(), 200);
+            assert!(sc.get_active_recovery().is_none());
+        })
+        .assert_ok();
+
+    ms_setup.b_mock.set_block_timestamp(201);
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &recovery_address,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut new_board = MultiValueEncoded::new();
+                new_board.push(managed_address!(&new_board_member));
+
+                sc.start_recovery(1, new_board);
+            },
+        )
+        .assert_ok();
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert!(sc.get_active_recovery().is_some());
+        })
+        .assert_ok();
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &recovery_address,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut new_board = MultiValueEncoded::new();
+                new_board.push(managed_address!(&new_board_member));
+
+                sc.start_recovery(1, new_board);
+            },
+        )
+        .assert_user_error("recovery already in progress");
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &recovery_address,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.finish_recovery();
+            },
+        )
+        .assert_user_error("challenge period has not passed");
+
+    // any board activity cancels the recovery
+    ms_setup.b_mock.set_block_timestamp(300);
+    let new_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let _ = ms_setup.propose_add_proposer(&new_proposer);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.last_board_activity().get(), 300);
+            assert!(sc.get_active_recovery().is_none());
+        })
+        .assert_ok();
+
+    ms_setup.b_mock.set_block_timestamp(300 + challenge_period);
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &recovery_address,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.finish_recovery();
+            },
+        )
+        .assert_user_error("no active recovery");
+
+    // board stays inactive for the whole challenge period
+    let start_timestamp = 400 + challenge_period;
+    ms_setup.b_mock.set_block_timestamp(start_timestamp);
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &recovery_address,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut new_board = MultiValueEncoded::new();
+                new_board.push(managed_address!(&new_board_member));
+
+                sc.start_recovery(1, new_board);
+            },
+        )
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .set_block_timestamp(start_timestamp + challenge_period);
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &recovery_address,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.finish_recovery();
+            },
+        )
+        .assert_ok();
+
+    ms_setup.expect_user_role(&new_board_member, UserRole::BoardMember);
+    ms_setup.expect_user_role(&ms_setup.first_board_member.clone(), UserRole::None);
+    ms_setup.expect_user_role(&ms_setup.second_board_member.clone(), UserRole::None);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.quorum().get(), 1);
+            assert_eq!(sc.num_board_members().get(), 1);
+            assert!(sc.pending_recovery().is_empty());
+
+            let board_members = sc.get_all_board_members().to_vec();
+            assert_eq!(board_members.len(), 1);
+            assert_eq!(
+                board_members.get(0).clone_value(),
+                managed_address!(&new_board_member)
+            );
+        })
+        .assert_ok();
+
+    // disabling recovery does not need a valid challenge period
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &new_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let action_id =
+                    sc.propose_set_recovery_config(0, None, None, MultiValueEncoded::new());
+                let _ = sc.perform_action_endpoint(action_id);
+            },
+        )
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert!(sc.recovery_addresses().is_empty());
+            assert_eq!(sc.recovery_challenge_period().get(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn member_activity_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    let first_board_member = ms_setup.first_board_member.clone();
+    let second_board_member = ms_setup.second_board_member.clone();
+
+    ms_setup.b_mock.set_block_timestamp(100);
+    ms_setup.b_mock.set_block_round(10);
+    let new_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let action_id = ms_setup.propose_add_proposer(&new_proposer);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(
+                sc.get_member_activity(managed_address!(&first_board_member)),
+                Some(MemberActivity {
+                    timestamp: 100,
+                    round: 10
+                })
+            );
+            assert_eq!(
+                sc.get_member_activity(managed_address!(&second_board_member)),
+                None
+            );
+
+            let inactive_members = sc.get_inactive_members(50).to_vec();
+            assert_eq!(inactive_members.len(), 1);
+            assert_eq!(
+                inactive_members.get(0).clone_value(),
+                managed_address!(&second_board_member)
+            );
+        })
+        .assert_ok();
+
+    ms_setup.b_mock.set_block_timestamp(200);
+    ms_setup.b_mock.set_block_round(20);
+    ms_setup.sign(action_id, 0);
+
+    ms_setup.b_mock.set_block_timestamp(300);
+    ms_setup.b_mock.set_block_round(30);
+    ms_setup.perform(action_id);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(
+                sc.get_member_activity(managed_address!(&second_board_member)),
+                Some(MemberActivity {
+                    timestamp: 200,
+                    round: 20
+                })
+            );
+            assert!(sc.get_inactive_members(200).is_empty());
+
+            let inactive_members = sc.get_inactive_members(250).to_vec();
+            assert_eq!(inactive_members.len(), 1);
+            assert_eq!(
+                inactive_members.get(0).clone_value(),
+                managed_address!(&second_board_member)
+            );
+
+            let overview = sc.get_board_overview().into_iter().collect::<Vec<_>>();
+            assert_eq!(overview.len(), 2);
+            assert_eq!(overview[0].address, managed_address!(&first_board_member));
+            assert_eq!(overview[0].weight, 1);
+            assert_eq!(
+                overview[0].last_activity,
+                Some(MemberActivity {
+                    timestamp: 300,
+                    round: 30
+                })
+            );
+            assert_eq!(overview[1].address, managed_address!(&second_board_member));
+            assert_eq!(
+                overview[1].last_activity.map(|activity| activity.timestamp),
+                Some(200)
+            );
+        })
+        .assert_ok();
+
+    // un-signing counts as activity
+    ms_setup.b_mock.set_block_timestamp(400);
+    let action_id = ms_setup.propose_add_proposer(&new_proposer);
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &second_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.unsign(action_id);
+            },
+        )
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert!(sc.get_inactive_members(400).is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn replace_board_member_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    let first_board_member = ms_setup.first_board_member.clone();
+    let second_board_member = ms_setup.second_board_member.clone();
+    let new_board_member = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    let action_id = ms_setup.propose_set_board_member_weight(&first_board_member, 3);
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform(action_id);
+
+    // pending action, only signed by the board member that gets replaced
+    let new_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let pending_action_id = ms_setup.propose_add_proposer(&new_proposer);
+
+    let action_id =
+        ms_setup.propose_replace_board_member(&first_board_member, &second_board_member, true);
+    ms_setup.sign(action_id, 1);
+    ms_setup.perform_and_expect_err(action_id, "new address already has a role");
+
+    let action_id = ms_setup.propose_replace_board_member(&new_proposer, &new_board_member, true);
+    ms_setup.sign(action_id, 2);
+    ms_setup.perform_and_expect_err(action_id, "old address is not a board member");
+
+    let action_id =
+        ms_setup.propose_replace_board_member(&first_board_member, &new_board_member, true);
+    ms_setup.sign(action_id, 3);
+    ms_setup.perform(action_id);
+
+    ms_setup.expect_user_role(&first_board_member, UserRole::None);
+    ms_setup.expect_user_role(&new_board_member, UserRole::BoardMember);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.num_board_members().get(), 2);
+            assert_eq!(
+                sc.get_board_member_weight(managed_address!(&new_board_member)),
+                3
+            );
+            assert_eq!(sc.get_total_board_weight_view(), 4);
+
+            assert!(!sc.signed(managed_address!(&first_board_member), pending_action_id));
+            assert!(sc.signed(managed_address!(&new_board_member), pending_action_id));
+            assert!(sc.quorum_reached(pending_action_id));
+
+            // the old address is cleaned up like any removed board member
+            let old_user_id = sc.user_ids().get_id(&managed_address!(&first_board_member));
+            let new_user_id = sc.user_ids().get_id(&managed_address!(&new_board_member));
+            assert!(sc.user_signed_actions(old_user_id).is_empty());
+            assert!(sc
+                .user_signed_actions(new_user_id)
+                .contains(&pending_action_id));
+            assert!(sc.user_weight(old_user_id).is_empty());
+            assert_eq!(
+                sc.get_member_activity(managed_address!(&first_board_member)),
+                None
+            );
+        })
+        .assert_ok();
+
+    // the old address can no longer act on behalf of the board
+    ms_setup.perform_and_expect_err(
+        pending_action_id,
+        "only board members, proposers and executors can perform actions",
+    );
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &new_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.perform_action_endpoint(pending_action_id);
+            },
+        )
+        .assert_ok();
+
+    ms_setup.expect_user_role(&new_proposer, UserRole::Proposer);
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            let new_user_id = sc.user_ids().get_id(&managed_address!(&new_board_member));
+            assert!(!sc
+                .user_signed_actions(new_user_id)
+                .contains(&pending_action_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn storage_migration_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    let first_board_member = ms_setup.first_board_member.clone();
+    let new_board_member = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let first_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let second_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let first_pending_action_id = ms_setup.propose_add_proposer(&first_proposer);
+    let second_pending_action_id = ms_setup.propose_add_proposer(&second_proposer);
+
+    // older versions kept neither the board nor the signatures of each user indexed
+    let ms_owner = ms_setup.ms_owner.clone();
+    ms_setup
+        .b_mock
+        .execute_tx(&ms_owner, &ms_setup.ms_wrapper, &rust_biguint!(0), |sc| {
+            sc.board_member_ids().clear();
+            for user_id in 1..=sc.user_ids().get_last_id() {
+                sc.user_signed_actions(user_id).clear();
+            }
+
+            sc.upgrade(OptionalValue::None, OptionalValue::None);
+            assert!(sc.storage_migration_pending().get());
+        })
+        .assert_ok();
+
+    let action_id =
+        ms_setup.propose_replace_board_member(&first_board_member, &new_board_member, true);
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform_and_expect_err(action_id, "storage migration pending");
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            let _ = sc.get_all_board_members();
+        })
+        .assert_user_error("storage migration pending");
+
+    // the two board members are indexed first, then the three actions
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &new_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                assert!(!sc.migrate_storage(2));
+                assert_eq!(sc.board_member_ids().len(), 2);
+                assert!(!sc.migrate_storage(2));
+                assert!(sc.migrate_storage(2));
+            },
+        )
+        .assert_ok();
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.get_all_board_members().len(), 2);
+        })
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &new_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.migrate_storage(1);
+            },
+        )
+        .assert_user_error("no storage migration pending");
+
+    // the signatures made before the upgrade are moved as well
+    ms_setup.perform(action_id);
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            for pending_action_id in [first_pending_action_id, second_pending_action_id] {
+                assert!(!sc.signed(managed_address!(&first_board_member), pending_action_id));
+                assert!(sc.signed(managed_address!(&new_board_member), pending_action_id));
+            }
+        })
+        .assert_ok();
+}
+
+#[test]
+fn reconfigure_board_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    let first_board_member = ms_setup.first_board_member.clone();
+    let second_board_member = ms_setup.second_board_member.clone();
+    let third_board_member = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let fourth_board_member = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let new_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    let action_id = ms_setup.propose_reconfigure_board(
+        &[],
+        &[first_board_member.clone(), second_board_member.clone()],
+        &[],
+        &[],
+        0,
+    );
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform_and_expect_err(action_id, "cannot remove all board members and proposers");
+
+    let action_id = ms_setup.propose_reconfigure_board(
+        &[],
+        std::slice::from_ref(&second_board_member),
+        &[],
+        &[],
+        2,
+    );
+    ms_setup.sign(action_id, 1);
+    ms_setup.perform_and_expect_err(action_id, "quorum cannot exceed total board weight");
+
+    let action_id =
+        ms_setup.propose_reconfigure_board(&[], std::slice::from_ref(&new_proposer), &[], &[], 2);
+    ms_setup.sign(action_id, 2);
+    ms_setup.perform_and_expect_err(action_id, "user to remove is not a board member");
+
+    // 2-of-2 to 3-of-3, with a 1-of-1 board in between
+    let action_id = ms_setup.propose_reconfigure_board(
+        &[third_board_member.clone(), fourth_board_member.clone()],
+        std::slice::from_ref(&second_board_member),
+        std::slice::from_ref(&new_proposer),
+        &[],
+        3,
+    );
+    ms_setup.sign(action_id, 3);
+    ms_setup.perform(action_id);
+
+    ms_setup.expect_user_role(&first_board_member, UserRole::BoardMember);
+    ms_setup.expect_user_role(&second_board_member, UserRole::None);
+    ms_setup.expect_user_role(&third_board_member, UserRole::BoardMember);
+    ms_setup.expect_user_role(&fourth_board_member, UserRole::BoardMember);
+    ms_setup.expect_user_role(&new_proposer, UserRole::Proposer);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.num_board_members().get(), 3);
+            assert_eq!(sc.num_proposers().get(), 1);
+            assert_eq!(sc.quorum().get(), 3);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn reconfigure_board_keeps_weight_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    let first_board_member = ms_setup.first_board_member.clone();
+    let second_board_member = ms_setup.second_board_member.clone();
+
+    let action_id = ms_setup.propose_set_board_member_weight(&second_board_member, 2);
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform(action_id);
+
+    // adding existing board members again leaves them untouched
+    let action_id = ms_setup.propose_reconfigure_board(
+        &[first_board_member.clone(), second_board_member.clone()],
+        &[],
+        &[],
+        &[],
+        3,
+    );
+    ms_setup.sign(action_id, 1);
+    ms_setup.perform(action_id);
+
+    ms_setup.expect_user_role(&first_board_member, UserRole::BoardMember);
+    ms_setup.expect_user_role(&second_board_member, UserRole::BoardMember);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(
+                sc.get_board_member_weight(managed_address!(&second_board_member)),
+                2
+            );
+            assert_eq!(sc.get_total_board_weight_view(), 3);
+            assert_eq!(sc.num_board_members().get(), 2);
+            assert_eq!(sc.quorum().get(), 3);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn explicit_role_change_actions_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    let first_board_member = ms_setup.first_board_member.clone();
+    let second_board_member = ms_setup.second_board_member.clone();
+    let user = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    let action_id = ms_setup.propose_add_proposer(&user);
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform(action_id);
+
+    let action_id = ms_setup.propose_remove_proposer(&first_board_member);
+    ms_setup.sign(action_id, 1);
+    ms_setup.perform_and_expect_err(action_id, "user is not a proposer");
+    ms_setup.expect_user_role(&first_board_member, UserRole::BoardMember);
+
+    let action_id = ms_setup.propose_promote_to_board_member(&user);
+    ms_setup.sign(action_id, 2);
+    ms_setup.perform(action_id);
+    ms_setup.expect_user_role(&user, UserRole::BoardMember);
+
+    let action_id = ms_setup.propose_promote_to_board_member(&user);
+    ms_setup.sign(action_id, 3);
+    ms_setup.perform_and_expect_err(action_id, "user is not a proposer");
+
+    let action_id = ms_setup.propose_demote_to_proposer(&user);
+    ms_setup.sign(action_id, 4);
+    ms_setup.perform(action_id);
+    ms_setup.expect_user_role(&user, UserRole::Proposer);
+
+    let action_id = ms_setup.propose_demote_to_proposer(&user);
+    ms_setup.sign(action_id, 5);
+    ms_setup.perform_and_expect_err(action_id, "user is not a board member");
+
+    // the quorum of 2 has to remain reachable
+    let action_id = ms_setup.propose_demote_to_proposer(&second_board_member);
+    ms_setup.sign(action_id, 6);
+    ms_setup.perform_and_expect_err(action_id, "quorum cannot exceed total board weight");
+
+    let action_id = ms_setup.propose_remove_proposer(&user);
+    ms_setup.sign(action_id, 7);
+    ms_setup.perform(action_id);
+    ms_setup.expect_user_role(&user, UserRole::None);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.num_board_members().get(), 2);
+            assert_eq!(sc.num_proposers().get(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn change_owner_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    let ms_address = ms_setup.ms_wrapper.address_ref().clone();
+    let new_owner = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    let action_id = ms_setup.propose_change_owner(&new_owner);
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform(action_id);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(
+                sc.blockchain().get_owner_address(),
+                managed_address!(&new_owner)
+            );
+        })
+        .assert_ok();
+
+    // contract deployed by the multisig
+    let managed_adder = ms_setup.b_mock.create_sc_account_fixed_address(
+        &sc_address(b"managed-adder"),
+        &rust_biguint!(0),
+        Some(&ms_address),
+        adder::contract_obj,
+        "managed adder",
+    );
+
+    let first_board_member = ms_setup.first_board_member.clone();
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.propose_change_managed_contract_owner(
+                    managed_address!(&new_owner),
+                    managed_address!(&new_owner),
+                    None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("Invalid SC address");
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let _ = sc.propose_change_managed_contract_owner(
+                    managed_address!(&ms_address),
+                    managed_address!(&new_owner),
+                    None,
+                    OptionalValue::None,
+                );
+            },
+        )
+        .assert_user_error("use proposeChangeOwner for the multisig itself");
+
+    let action_id =
+        ms_setup.propose_change_managed_contract_owner(managed_adder.address_ref(), &new_owner);
+    ms_setup.sign(action_id, 1);
+    ms_setup.perform(action_id);
+
+    ms_setup
+        .b_mock
+        .execute_query(&managed_adder, |sc| {
+            assert_eq!(
+                sc.blockchain().get_owner_address(),
+                managed_address!(&new_owner)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn configurable_limits_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    let new_board_member = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let adder_address = ms_setup.adder_wrapper.address_ref().clone();
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.max_board_members().get(), DEFAULT_MAX_BOARD_MEMBERS);
+            assert_eq!(sc.max_modules().get(), DEFAULT_MAX_MODULES);
+        })
+        .assert_ok();
+
+    let action_id = ms_setup.propose_set_max_board_members(1);
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform_and_expect_err(action_id, "max board members cannot be below board size");
+
+    let action_id = ms_setup.propose_set_max_board_members(MAX_BOARD_MEMBERS_LIMIT + 1);
+    ms_setup.sign(action_id, 1);
+    ms_setup.perform_and_expect_err(action_id, "invalid max board members");
+
+    let action_id = ms_setup.propose_set_max_board_members(2);
+    ms_setup.sign(action_id, 2);
+    ms_setup.perform(action_id);
+
+    let action_id = ms_setup.propose_add_board_member(&new_board_member);
+    ms_setup.sign(action_id, 3);
+    ms_setup.perform_and_expect_err(action_id, "board size cannot exceed limit");
+
+    let action_id = ms_setup.propose_set_max_modules(1);
+    ms_setup.sign(action_id, 4);
+    ms_setup.perform(action_id);
+
+    let action_id = ms_setup.propose_add_module(&adder_address);
+    ms_setup.sign(action_id, 5);
+    ms_setup.perform(action_id);
+
+    let second_module = ms_setup.b_mock.create_sc_account_fixed_address(
+        &sc_address(b"second-module"),
+        &rust_biguint!(0),
+        None,
+        adder::contract_obj,
+        "second module",
+    );
+    let action_id = ms_setup.propose_add_module(second_module.address_ref());
+    ms_setup.sign(action_id, 6);
+    ms_setup.perform_and_expect_err(action_id, "May not add more modules");
+
+    let action_id = ms_setup.propose_set_max_modules(0);
+    ms_setup.sign(action_id, 7);
+    ms_setup.perform_and_expect_err(action_id, "invalid max modules");
+
+    // limits can also be set on upgrade, missing ones are kept
+    let ms_owner = ms_setup.ms_owner.clone();
+    ms_setup
+        .b_mock
+        .execute_tx(&ms_owner, &ms_setup.ms_wrapper, &rust_biguint!(0), |sc| {
+            sc.upgrade(OptionalValue::Some(40), OptionalValue::None);
+
+            assert_eq!(sc.max_board_members().get(), 40);
+            assert_eq!(sc.max_modules().get(), 1);
+        })
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_tx(&ms_owner, &ms_setup.ms_wrapper, &rust_biguint!(0), |sc| {
+            sc.upgrade(
+                OptionalValue::None,
+                OptionalValue::Some(MAX_MODULES_LIMIT + 1),
+            );
+        })
+        .assert_user_error("invalid max modules");
+}
+
+#[test]
+fn batch_order_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+
+    let first_user = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let second_user = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let mut group_id = 0;
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                // each action depends on the previous one being performed first
+                let mut actions = MultiValueEncoded::new();
+                actions.push(Action::AddProposer(managed_address!(&first_user)));
+                actions.push(Action::PromoteToBoardMember(managed_address!(&first_user)));
+                actions.push(Action::AddProposer(managed_address!(&second_user)));
+                actions.push(Action::RemoveProposer(managed_address!(&second_user)));
+
+                group_id = sc.propose_batch(None, actions).into_option().unwrap();
+            },
+        )
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            let action_ids: Vec<usize> =
+                sc.get_action_group_ordered(group_id).into_iter().collect();
+            assert_eq!(action_ids, vec![1, 2, 3, 4]);
+        })
+        .assert_ok();
+
+    // nothing is performed unless every action can be performed
+    ms_setup.sign(1, 0);
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.perform_batch(group_id);
+            },
+        )
+        .assert_user_error("quorum has not been reached");
+    ms_setup.expect_user_role(&first_user, UserRole::None);
+
+    for (signer_nonce, action_id) in [(1, 2), (2, 3), (3, 4)] {
+        ms_setup.sign(action_id, signer_nonce);
+    }
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.perform_batch(group_id);
+            },
+        )
+        .assert_ok();
+
+    ms_setup.expect_user_role(&first_user, UserRole::BoardMember);
+    ms_setup.expect_user_role(&second_user, UserRole::None);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.get_action_group_ordered(group_id).len(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn batch_with_upgrade_and_async_call_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    let adder_address = ms_setup.adder_wrapper.address_ref().clone();
+    let new_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    let add_call_data = |arguments: Vec<&[u8]>| {
+        let mut managed_arguments = ManagedVec::new();
+        for argument in arguments {
+            managed_arguments.push(managed_buffer!(argument));
+        }
+
+        CallActionData {
+            to: managed_address!(&adder_address),
+            egld_amount: managed_biguint!(0),
+            opt_gas_limit: None,
+            endpoint_name: managed_buffer!(b"add"),
+            arguments: managed_arguments,
+        }
+    };
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut actions = MultiValueEncoded::new();
+                actions.push(Action::SendAsyncCall(add_call_data(vec![&[5u8][..]])));
+                actions.push(Action::AddProposer(managed_address!(&new_proposer)));
+
+                let _ = sc.propose_batch(None, actions);
+            },
+        )
+        .assert_user_error("async calls and upgrades can only be the last action of a batch");
+
+    let action_id = ms_setup.propose_sc_deploy_from_source(
+        0,
+        &adder_address,
+        CodeMetadata::all(),
+        [&[0u8][..]].to_vec(),
+    );
+    ms_setup.sign(action_id, 0);
+
+    let new_adder_wrapper = ms_setup.prepare_deploy_from_ms(adder::contract_obj);
+    ms_setup.perform(action_id);
+
+    let factorial_wrapper = ms_setup.b_mock.create_sc_account_fixed_address(
+        &sc_address(b"factorial"),
+        &rust_biguint!(0),
+        Some(&ms_setup.first_board_member),
+        factorial::contract_obj,
+        "factorial",
+    );
+    let upgrade_action = || Action::SCUpgradeFromSource {
+        sc_address: managed_address!(new_adder_wrapper.address_ref()),
+        args: DeployArgs {
+            amount: managed_biguint!(0),
+            source: managed_address!(factorial_wrapper.address_ref()),
+            code_metadata: CodeMetadata::all(),
+            arguments: ManagedVec::new(),
+        },
+    };
+
+    // the upgrade is an async call as well, so nothing can follow it
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut actions = MultiValueEncoded::new();
+                actions.push(upgrade_action());
+                actions.push(Action::SendAsyncCall(add_call_data(vec![&[5u8][..]])));
+
+                let _ = sc.propose_batch(None, actions);
+            },
+        )
+        .assert_user_error("async calls and upgrades can only be the last action of a batch");
+
+    // a synchronous call, then an upgrade to complete the batch
+    let mut group_id = 0;
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut actions = MultiValueEncoded::new();
+                actions.push(Action::SendTransferExecuteEgld(add_call_data(vec![
+                    &[5u8][..],
+                ])));
+                actions.push(upgrade_action());
+
+                group_id = sc.propose_batch(None, actions).into_option().unwrap();
+            },
+        )
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(
+                sc.get_group_execution_status(group_id),
+                GroupExecutionStatus::NotPerformed
+            );
+            assert!(!sc.is_batch_successful(group_id));
+        })
+        .assert_ok();
+
+    for (signer_nonce, action_id) in [(1, 2), (2, 3)] {
+        ms_setup.sign(action_id, signer_nonce);
+    }
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.perform_batch(group_id);
+            },
+        )
+        .assert_ok();
+
+    let deployed_factorial_wrapper = ms_setup
+        .b_mock
+        .upgrade_wrapper(new_adder_wrapper, factorial::contract_obj);
+    ms_setup
+        .b_mock
+        .execute_query(&deployed_factorial_wrapper, |sc| {
+            assert_eq!(sc.factorial(managed_biguint!(5)), 120);
+        })
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(
+                sc.get_group_execution_status(group_id),
+                GroupExecutionStatus::Succeeded
+            );
+            assert!(sc.is_batch_successful(group_id));
+        })
+        .assert_ok();
+
+    // the async call completes the batch
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut actions = MultiValueEncoded::new();
+                actions.push(Action::SendTransferExecuteEgld(add_call_data(vec![
+                    &[5u8][..],
+                ])));
+                actions.push(Action::SendAsyncCall(add_call_data(vec![&[5u8][..]])));
+
+                group_id = sc.propose_batch(None, actions).into_option().unwrap();
+            },
+        )
+        .assert_ok();
+
+    for (signer_nonce, action_id) in [(3, 4), (4, 5)] {
+        ms_setup.sign(action_id, signer_nonce);
+    }
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.perform_batch(group_id);
+            },
+        )
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.adder_wrapper, |sc| {
+            assert_eq!(sc.sum().get(), 15);
+        })
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert!(sc.is_batch_successful(group_id));
+        })
+        .assert_ok();
+
+    // a failed async call fails the batch, even though the previous actions were performed
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut actions = MultiValueEncoded::new();
+                actions.push(Action::AddProposer(managed_address!(&new_proposer)));
+                actions.push(Action::SendAsyncCall(add_call_data(Vec::new())));
+
+                group_id = sc.propose_batch(None, actions).into_option().unwrap();
+            },
+        )
+        .assert_ok();
+
+    for (signer_nonce, action_id) in [(5, 6), (6, 7)] {
+        ms_setup.sign(action_id, signer_nonce);
+    }
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.perform_batch(group_id);
+            },
+        )
+        .assert_ok();
+
+    ms_setup.expect_user_role(&new_proposer, UserRole::Proposer);
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(
+                sc.get_group_execution_status(group_id),
+                GroupExecutionStatus::Failed
+            );
+            assert!(!sc.is_batch_successful(group_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn batch_ending_with_upgrade_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    let adder_address = ms_setup.adder_wrapper.address_ref().clone();
+    let first_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+    let second_proposer = ms_setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    let action_id = ms_setup.propose_sc_deploy_from_source(
+        0,
+        &adder_address,
+        CodeMetadata::all(),
+        [&[0u8][..]].to_vec(),
+    );
+    ms_setup.sign(action_id, 0);
+
+    let new_adder_wrapper = ms_setup.prepare_deploy_from_ms(adder::contract_obj);
+    ms_setup.perform(action_id);
+
+    let factorial_wrapper = ms_setup.b_mock.create_sc_account_fixed_address(
+        &sc_address(b"factorial"),
+        &rust_biguint!(0),
+        Some(&ms_setup.first_board_member),
+        factorial::contract_obj,
+        "factorial",
+    );
+    let new_adder_address = new_adder_wrapper.address_ref().clone();
+    let factorial_address = factorial_wrapper.address_ref().clone();
+    let upgrade_action = |source: &Address| Action::SCUpgradeFromSource {
+        sc_address: managed_address!(&new_adder_address),
+        args: DeployArgs {
+            amount: managed_biguint!(0),
+            source: managed_address!(source),
+            code_metadata: CodeMetadata::all(),
+            arguments: ManagedVec::new(),
+        },
+    };
+
+    // the upgrade completes the batch once its callback is received
+    let mut group_id = 0;
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut actions = MultiValueEncoded::new();
+                actions.push(Action::AddProposer(managed_address!(&first_proposer)));
+                actions.push(upgrade_action(&factorial_address));
+
+                group_id = sc.propose_batch(None, actions).into_option().unwrap();
+            },
+        )
+        .assert_ok();
+
+    for (signer_nonce, action_id) in [(1, 2), (2, 3)] {
+        ms_setup.sign(action_id, signer_nonce);
+    }
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.perform_batch(group_id);
+            },
+        )
+        .assert_ok();
+
+    let deployed_factorial_wrapper = ms_setup
+        .b_mock
+        .upgrade_wrapper(new_adder_wrapper, factorial::contract_obj);
+    ms_setup
+        .b_mock
+        .execute_query(&deployed_factorial_wrapper, |sc| {
+            assert_eq!(sc.factorial(managed_biguint!(5)), 120);
+        })
+        .assert_ok();
+
+    ms_setup.expect_user_role(&first_proposer, UserRole::Proposer);
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert!(sc.get_action_execution_result(3).unwrap().success);
+            assert_eq!(
+                sc.get_group_execution_status(group_id),
+                GroupExecutionStatus::Succeeded
+            );
+            assert!(sc.is_batch_successful(group_id));
+        })
+        .assert_ok();
+
+    // a failed upgrade completes the batch as well, as failed
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut actions = MultiValueEncoded::new();
+                actions.push(Action::AddProposer(managed_address!(&second_proposer)));
+                actions.push(upgrade_action(&adder_address));
+
+                group_id = sc.propose_batch(None, actions).into_option().unwrap();
+            },
+        )
+        .assert_ok();
+
+    for (signer_nonce, action_id) in [(3, 4), (4, 5)] {
+        ms_setup.sign(action_id, signer_nonce);
+    }
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.perform_batch(group_id);
+            },
+        )
+        .assert_ok();
+
+    ms_setup.expect_user_role(&second_proposer, UserRole::Proposer);
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert!(!sc.get_action_execution_result(5).unwrap().success);
+            assert_eq!(
+                sc.get_group_execution_status(group_id),
+                GroupExecutionStatus::Failed
+            );
+            assert!(!sc.is_batch_successful(group_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn promise_call_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    let adder_address = ms_setup.adder_wrapper.address_ref().clone();
+
+    let action_id =
+        ms_setup.propose_promise_call(&adder_address, 0, 10_000_000, b"add", vec![&[5u8][..]]);
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform(action_id);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(
+                sc.get_promise_call_status(action_id),
+                PromiseCallStatus::Succeeded
+            );
+        })
+        .assert_ok();
+
+    // "sc:" addresses end with '_', which is in shard 2
+    let mut other_shard_address = sc_address(b"adder").to_vec();
+    other_shard_address[31] = 0;
+    let other_shard_address = Address::from_slice(&other_shard_address);
+    let other_shard_adder = ms_setup.b_mock.create_sc_account_fixed_address(
+        &other_shard_address,
+        &rust_biguint!(0),
+        None,
+        adder::contract_obj,
+        "other shard adder",
+    );
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &other_shard_adder,
+            &rust_biguint!(0),
+            |sc| {
+                sc.init(managed_biguint!(0));
+            },
+        )
+        .assert_ok();
+
+    let promise_call = |to: &Address, opt_gas_limit: Option<u64>, arguments: Vec<&[u8]>| {
+        let mut managed_arguments = ManagedVec::new();
+        for argument in arguments {
+            managed_arguments.push(managed_buffer!(argument));
+        }
+
+        Action::SendPromiseCallEgld(CallActionData {
+            to: managed_address!(to),
+            egld_amount: managed_biguint!(0),
+            opt_gas_limit,
+            endpoint_name: managed_buffer!(b"add"),
+            arguments: managed_arguments,
+        })
+    };
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut actions = MultiValueEncoded::new();
+                actions.push(promise_call(&other_shard_address, None, vec![&[5u8][..]]));
+
+                let _ = sc.propose_batch(None, actions);
+            },
+        )
+        .assert_user_error("gas limit required for promise calls");
+
+    // several calls to any shard in the same batch, each with its own result
+    let mut group_id = 0;
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut actions = MultiValueEncoded::new();
+                actions.push(promise_call(
+                    &other_shard_address,
+                    Some(10_000_000),
+                    vec![&[5u8][..]],
+                ));
+                actions.push(promise_call(&adder_address, Some(10_000_000), Vec::new()));
+                actions.push(promise_call(
+                    &adder_address,
+                    Some(10_000_000),
+                    vec![&[7u8][..]],
+                ));
+
+                group_id = sc.propose_batch(None, actions).into_option().unwrap();
+            },
+        )
+        .assert_ok();
+
+    for (signer_nonce, action_id) in [(1, 2), (2, 3), (3, 4)] {
+        ms_setup.sign(action_id, signer_nonce);
+    }
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.perform_batch(group_id);
+            },
+        )
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_query(&other_shard_adder, |sc| {
+            assert_eq!(sc.sum().get(), 5);
+        })
+        .assert_ok();
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.adder_wrapper, |sc| {
+            assert_eq!(sc.sum().get(), 12);
+        })
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.get_promise_call_status(2), PromiseCallStatus::Succeeded);
+            assert_eq!(sc.get_promise_call_status(3), PromiseCallStatus::Failed);
+            assert_eq!(sc.get_promise_call_status(4), PromiseCallStatus::Succeeded);
+            assert_eq!(sc.get_promise_call_status(5), PromiseCallStatus::None);
+
+            // one failed call fails the whole batch
+            assert_eq!(
+                sc.get_group_execution_status(group_id),
+                GroupExecutionStatus::Failed
+            );
+            assert_eq!(sc.group_pending_promise_calls(group_id).get(), 0);
+        })
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut actions = MultiValueEncoded::new();
+                actions.push(promise_call(
+                    &adder_address,
+                    Some(10_000_000),
+                    vec![&[1u8][..]],
+                ));
+                actions.push(Action::SendAsyncCall(CallActionData {
+                    to: managed_address!(&adder_address),
+                    egld_amount: managed_biguint!(0),
+                    opt_gas_limit: None,
+                    endpoint_name: managed_buffer!(b"add"),
+                    arguments: ManagedVec::from_single_item(managed_buffer!(&[1u8])),
+                }));
+
+                let _ = sc.propose_batch(None, actions);
+            },
+        )
+        .assert_user_error("promise calls cannot be combined with async calls or upgrades");
+
+    // the batch only succeeds once all the callbacks were received
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut actions = MultiValueEncoded::new();
+                actions.push(promise_call(
+                    &other_shard_address,
+                    Some(10_000_000),
+                    vec![&[1u8][..]],
+                ));
+                actions.push(promise_call(
+                    &adder_address,
+                    Some(10_000_000),
+                    vec![&[1u8][..]],
+                ));
+
+                group_id = sc.propose_batch(None, actions).into_option().unwrap();
+            },
+        )
+        .assert_ok();
+
+    for (signer_nonce, action_id) in [(4, 5), (5, 6)] {
+        ms_setup.sign(action_id, signer_nonce);
+    }
+
+    ms_setup
+        .b_mock
+        .execute_tx(
+            &ms_setup.first_board_member,
+            &ms_setup.ms_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.perform_batch(group_id);
+            },
+        )
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert_eq!(sc.get_promise_call_status(5), PromiseCallStatus::Succeeded);
+            assert_eq!(sc.get_promise_call_status(6), PromiseCallStatus::Succeeded);
+            assert_eq!(
+                sc.get_group_execution_status(group_id),
+                GroupExecutionStatus::Succeeded
+            );
+            assert!(sc.is_batch_successful(group_id));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn action_execution_result_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    let adder_address = ms_setup.adder_wrapper.address_ref().clone();
+
+    let add_action_id = ms_setup.propose_async_call(&adder_address, 0, b"add", vec![&[5u8][..]]);
+    ms_setup.sign(add_action_id, 0);
+    ms_setup.perform(add_action_id);
+
+    let get_sum_action_id = ms_setup.propose_async_call(&adder_address, 0, b"getSum", Vec::new());
+    ms_setup.sign(get_sum_action_id, 1);
+    ms_setup.perform(get_sum_action_id);
+
+    let failed_action_id = ms_setup.propose_async_call(&adder_address, 0, b"add", Vec::new());
+    ms_setup.sign(failed_action_id, 2);
+    ms_setup.perform(failed_action_id);
+
+    let promise_action_id =
+        ms_setup.propose_promise_call(&adder_address, 0, 10_000_000, b"getSum", Vec::new());
+    ms_setup.sign(promise_action_id, 3);
+    ms_setup.perform(promise_action_id);
+
+    let action_id = ms_setup.propose_change_quorum(1);
+    ms_setup.sign(action_id, 4);
+    ms_setup.perform(action_id);
+
+    // only the beginning of long error messages is stored
+    let failing_call_mock = ms_setup.b_mock.create_sc_account_fixed_address(
+        &sc_address(b"failing-call-mock"),
+        &rust_biguint!(0),
+        Some(&ms_setup.ms_owner),
+        FailingCallMock::new,
+        "failing call mock",
+    );
+    let long_error_action_id =
+        ms_setup.propose_async_call(failing_call_mock.address_ref(), 0, b"fail", Vec::new());
+    ms_setup.perform(long_error_action_id);
+
+    // the returned data is hashed as a nested encoded list of buffers
+    let empty_results_hash = Sha256::digest([]);
+    let sum_results_hash = Sha256::digest([0, 0, 0, 1, 5]);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            let result = sc.get_action_execution_result(add_action_id).unwrap();
+            assert!(result.success);
+            assert_eq!(result.err_code, 0);
+            assert!(result.err_message.is_empty());
+            assert_eq!(
+                result.return_data_hash.to_byte_array(),
+                empty_results_hash.as_slice()
+            );
+
+            let result = sc.get_action_execution_result(get_sum_action_id).unwrap();
+            assert!(result.success);
+            assert_eq!(
+                result.return_data_hash.to_byte_array(),
+                sum_results_hash.as_slice()
+            );
+
+            let result = sc.get_action_execution_result(failed_action_id).unwrap();
+            assert!(!result.success);
+            assert_ne!(result.err_code, 0);
+            assert_eq!(
+                result.err_message,
+                managed_buffer!(b"wrong number of arguments")
+            );
+            assert_eq!(result.return_data_hash.to_byte_array(), [0u8; 32]);
+
+            let result = sc.get_action_execution_result(promise_action_id).unwrap();
+            assert!(result.success);
+            assert_eq!(
+                result.return_data_hash.to_byte_array(),
+                sum_results_hash.as_slice()
+            );
+
+            let result = sc
+                .get_action_execution_result(long_error_action_id)
+                .unwrap();
+            assert!(!result.success);
+            assert_eq!(
+                result.err_message,
+                managed_buffer!(&FAIL_ERR_MESSAGE[..MAX_STORED_ERR_MESSAGE_LEN])
+            );
+
+            // synchronous actions have no result record
+            assert!(sc.get_action_execution_result(action_id).is_none());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn async_call_retry_test() {
+    let mut ms_setup = MsImprovedSetup::new(multisig_improved::contract_obj, adder::contract_obj);
+    let adder_address = ms_setup.adder_wrapper.address_ref().clone();
+
+    let action_id = ms_setup.propose_set_max_async_call_retries(11);
+    ms_setup.sign(action_id, 0);
+    ms_setup.perform_and_expect_err(action_id, "max async call retries too big");
+
+    let action_id = ms_setup.propose_set_max_async_call_retries(1);
+    ms_setup.sign(action_id, 1);
+    ms_setup.perform(action_id);
+
+    // fails because of the missing argument, but is kept with its signatures
+    let failed_action_id = ms_setup.propose_async_call(&adder_address, 0, b"add", Vec::new());
+    ms_setup.sign(failed_action_id, 2);
+    ms_setup.perform(failed_action_id);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert!(!sc.action_mapper().item_is_empty(failed_action_id));
+            assert_eq!(sc.get_action_signer_count(failed_action_id), 2);
+            assert_eq!(
+                sc.get_async_call_retry_info(failed_action_id),
+                Some(AsyncCallRetryInfo {
+                    attempts: 1,
+                    in_progress: false,
+                })
+            );
+            assert!(
+                !sc.get_action_execution_result(failed_action_id)
+                    .unwrap()
+                    .success
+            );
+        })
+        .assert_ok();
+
+    // the last retry fails as well, so the action is cleared
+    ms_setup.perform(failed_action_id);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert!(sc.action_mapper().item_is_empty(failed_action_id));
+            assert_eq!(sc.get_action_signer_count(failed_action_id), 0);
+            assert!(sc.get_async_call_retry_info(failed_action_id).is_none());
+        })
+        .assert_ok();
+
+    // successful calls are cleared right away
+    let add_action_id = ms_setup.propose_async_call(&adder_address, 0, b"add", vec![&[5u8][..]]);
+    ms_setup.sign(add_action_id, 3);
+    ms_setup.perform(add_action_id);
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.ms_wrapper, |sc| {
+            assert!(sc.action_mapper().item_is_empty(add_action_id));
+            assert!(sc.get_async_call_retry_info(add_action_id).is_none());
+            assert!(
+                sc.get_action_execution_result(add_action_id)
+                    .unwrap()
+                    .success
+            );
+        })
+        .assert_ok();
+
+    ms_setup
+        .b_mock
+        .execute_query(&ms_setup.adder_wrapper, |sc| {
+            assert_eq!(sc.sum().get(), managed_biguint!(5));
+        })
+        .assert_ok();
+}
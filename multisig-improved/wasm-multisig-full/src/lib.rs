@@ -6,9 +6,10 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           44
+// Endpoints:                          108
 // Async Callback:                       1
-// Total number of exported functions:  47
+// Promise callbacks:                    1
+// Total number of exported functions: 112
 
 #![no_std]
 
@@ -22,28 +23,68 @@ multiversx_sc_wasm_adapter::endpoints! {
         upgrade => upgrade
         deposit => deposit
         getQuorum => quorum
+        getActionDeadline => action_deadline
+        getExecutionDelay => execution_delay
+        getActionKindExecutionDelay => action_kind_execution_delay
         getNumBoardMembers => num_board_members
+        getMaxBoardMembers => max_board_members
+        getMaxAsyncCallRetries => max_async_call_retries
         getNumGroups => num_groups
         getNumProposers => num_proposers
+        getNumGuardians => num_guardians
         getActionGroup => action_groups
         getLastGroupActionId => last_action_group_id
+        isStorageMigrationPending => storage_migration_pending
+        isPaused => paused
+        getLastBoardActivity => last_board_activity
+        getRecoveryAddresses => recovery_addresses
+        getRecoveryChallengePeriod => recovery_challenge_period
         quorumReached => quorum_reached
         proposeAddBoardMember => propose_add_board_member
         proposeAddProposer => propose_add_proposer
+        proposeAddGuardian => propose_add_guardian
+        proposeAddExecutor => propose_add_executor
+        proposeReplaceBoardMember => propose_replace_board_member
+        proposeReconfigureBoard => propose_reconfigure_board
         proposeRemoveUser => propose_remove_user
+        proposeRemoveProposer => propose_remove_proposer
+        proposeDemoteToProposer => propose_demote_to_proposer
+        proposePromoteToBoardMember => propose_promote_to_board_member
         proposeChangeQuorum => propose_change_quorum
         proposeTransferExecute => propose_transfer_execute
         proposeTransferExecuteEsdt => propose_transfer_execute_esdt
         proposeAsyncCall => propose_async_call
+        proposePromiseCall => propose_promise_call
+        proposePromiseCallEsdt => propose_promise_call_esdt
         proposeSCDeployFromSource => propose_sc_deploy_from_source
         proposeSCUpgradeFromSource => propose_sc_upgrade_from_source
+        proposeChangeOwner => propose_change_owner
+        proposeChangeManagedContractOwner => propose_change_managed_contract_owner
         proposeAddModule => propose_add_module
         proposeRemoveModule => propose_remove_module
+        proposeSetModuleNotifications => propose_set_module_notifications
+        proposeSetModuleActionApproval => propose_set_module_action_approval
+        proposeUnpause => propose_unpause
+        proposeSetRecoveryConfig => propose_set_recovery_config
+        proposeSetMaxBoardMembers => propose_set_max_board_members
+        proposeSetMaxModules => propose_set_max_modules
+        proposeSetMaxAsyncCallRetries => propose_set_max_async_call_retries
+        proposeSetExecutionDelay => propose_set_execution_delay
+        proposeSetActionKindExecutionDelay => propose_set_action_kind_execution_delay
+        proposeChangeActionKindQuorum => propose_change_action_kind_quorum
+        proposeSetBoardMemberWeight => propose_set_board_member_weight
         proposeBatch => propose_batch
         performAction => perform_action_endpoint
         performBatch => perform_batch
         discardAction => discard_action_endpoint
         discardBatch => discard_batch
+        vetoAction => veto_action_endpoint
+        discardExpiredActions => discard_expired_actions
+        migrateStorage => migrate_storage
+        pause => pause
+        startRecovery => start_recovery
+        finishRecovery => finish_recovery
+        getActiveRecovery => get_active_recovery
         sign => sign
         signBatch => sign_batch
         signAndPerform => sign_and_perform
@@ -51,19 +92,44 @@ multiversx_sc_wasm_adapter::endpoints! {
         unsign => unsign
         unsignBatch => unsign_batch
         unsignForOutdatedBoardMembers => unsign_for_outdated_board_members
+        registerSigningKey => register_signing_key
+        getModuleNotificationsEnabled => get_module_notifications_enabled
+        getModuleActionApprovalEnabled => get_module_action_approval_enabled
         getNrDeployedModules => nr_deployed_modules
+        getMaxModules => max_modules
         signed => signed
         getActionLastIndex => get_action_last_index
+        getActionKindQuorum => get_action_kind_quorum
         getUserNonce => get_user_nonce
+        getSigningKey => get_signing_key_view
         dnsRegister => dns_register
         getPendingActionFullInfo => get_pending_action_full_info
         getActionSignerCount => get_action_signer_count
         getActionValidSignerCount => get_action_valid_signer_count_view
+        getActionValidSignerWeight => get_action_valid_signer_weight_view
         getActionSigners => get_action_signers_view
         userRole => user_role
         getAllBoardMembers => get_all_board_members
+        getAllBoardMembersWithWeight => get_all_board_members_with_weight
+        getBoardOverview => get_board_overview
+        getMemberActivity => get_member_activity
+        getInactiveMembers => get_inactive_members
+        getBoardMemberWeight => get_board_member_weight
+        getTotalBoardWeight => get_total_board_weight_view
         getAllProposers => get_all_proposers
+        getAllGuardians => get_all_guardians
+        getAllExecutors => get_all_executors
         getActionData => get_action_data
+        getEarliestExecutionTime => get_earliest_execution_time_view
+        getActionGroupOrdered => get_action_group_ordered
+        getGroupExecutionStatus => get_group_execution_status
+        isBatchSuccessful => is_batch_successful
+        getPromiseCallStatus => get_promise_call_status
+        getActionExecutionResult => get_action_execution_result
+        getAsyncCallRetryInfo => get_async_call_retry_info
+        getGroupEarliestExecutionTime => get_group_earliest_execution_time
+        getRecentModuleExecutions => get_recent_module_executions
+        perform_promise_call_callback => perform_promise_call_callback
     )
 }
 
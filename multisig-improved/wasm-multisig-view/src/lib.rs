@@ -5,9 +5,9 @@
 ////////////////////////////////////////////////////
 
 // Init:                                 1
-// Endpoints:                            8
+// Endpoints:                           26
 // Async Callback (empty):               1
-// Total number of exported functions:  10
+// Total number of exported functions:  28
 
 #![no_std]
 
@@ -22,11 +22,29 @@ multiversx_sc_wasm_adapter::external_view_endpoints! {
         getPendingActionFullInfo => get_pending_action_full_info
         getActionSignerCount => get_action_signer_count
         getActionValidSignerCount => get_action_valid_signer_count_view
+        getActionValidSignerWeight => get_action_valid_signer_weight_view
         getActionSigners => get_action_signers_view
         userRole => user_role
         getAllBoardMembers => get_all_board_members
+        getAllBoardMembersWithWeight => get_all_board_members_with_weight
+        getBoardOverview => get_board_overview
+        getMemberActivity => get_member_activity
+        getInactiveMembers => get_inactive_members
+        getBoardMemberWeight => get_board_member_weight
+        getTotalBoardWeight => get_total_board_weight_view
         getAllProposers => get_all_proposers
+        getAllGuardians => get_all_guardians
+        getAllExecutors => get_all_executors
         getActionData => get_action_data
+        getEarliestExecutionTime => get_earliest_execution_time_view
+        getActionGroupOrdered => get_action_group_ordered
+        getGroupExecutionStatus => get_group_execution_status
+        isBatchSuccessful => is_batch_successful
+        getPromiseCallStatus => get_promise_call_status
+        getActionExecutionResult => get_action_execution_result
+        getAsyncCallRetryInfo => get_async_call_retry_info
+        getGroupEarliestExecutionTime => get_group_earliest_execution_time
+        getRecentModuleExecutions => get_recent_module_executions
     )
 }
 
@@ -1,5 +1,7 @@
 use adder::Adder;
+use ed25519_dalek::{Signer, SigningKey};
 use multisig_improved::{
+    check_signature::CheckSignatureModule,
     common_types::{
         action::{ActionId, Nonce},
         signature::{ActionType, SignatureArg, SignatureType},
@@ -7,6 +9,7 @@ use multisig_improved::{
     ms_endpoints::{
         perform::PerformEndpointsModule, propose::ProposeEndpointsModule, sign::SignEndpointsModule,
     },
+    state::StateModule,
     Multisig,
 };
 use multiversx_sc::{
@@ -19,6 +22,7 @@ use multiversx_sc_scenario::{
     managed_address, managed_biguint, managed_buffer, rust_biguint, DebugApi,
 };
 use passthrough::Passthrough;
+use test_utils::sc_address;
 
 pub struct PassSetup<PassThroughBuilder, MsImprovedBuilder, AdderBuilder>
 where
@@ -29,6 +33,7 @@ where
     pub b_mock: BlockchainStateWrapper,
     pub first_board_member: Address,
     pub second_board_member: Address,
+    pub second_board_member_key: SigningKey,
     pub ms_owner: Address,
     pub pass_wrapper: ContractObjWrapper<passthrough::ContractObj<DebugApi>, PassThroughBuilder>,
     pub ms_wrapper: ContractObjWrapper<multisig_improved::ContractObj<DebugApi>, MsImprovedBuilder>,
@@ -50,18 +55,34 @@ where
         let rust_zero = rust_biguint!(0u64);
         let mut b_mock = BlockchainStateWrapper::new();
         let first_board_member = b_mock.create_user_account(&rust_zero);
-        let second_board_member = b_mock.create_user_account(&rust_zero);
+
+        // the signing board member is an Ed25519 account, its address is its public key
+        let second_board_member_key = SigningKey::from_bytes(&[2u8; 32]);
+        let second_board_member = Address::from(second_board_member_key.verifying_key().to_bytes());
+        b_mock.create_user_account_fixed_address(&second_board_member, &rust_zero);
+
         let ms_owner = b_mock.create_user_account(&rust_zero);
-        let adder_wrapper = b_mock.create_sc_account(
+        let adder_wrapper = b_mock.create_sc_account_fixed_address(
+            &sc_address(b"adder"),
             &rust_zero,
             Some(&first_board_member),
             adder_builder,
             "adder",
         );
-        let ms_wrapper =
-            b_mock.create_sc_account(&rust_zero, Some(&ms_owner), ms_builder, "multisig");
-        let pass_wrapper =
-            b_mock.create_sc_account(&rust_zero, Some(&ms_owner), pass_builder, "passthrough");
+        let ms_wrapper = b_mock.create_sc_account_fixed_address(
+            &sc_address(b"multisig"),
+            &rust_zero,
+            Some(&ms_owner),
+            ms_builder,
+            "multisig",
+        );
+        let pass_wrapper = b_mock.create_sc_account_fixed_address(
+            &sc_address(b"passthrough"),
+            &rust_zero,
+            Some(&ms_owner),
+            pass_builder,
+            "passthrough",
+        );
 
         // init adder
         b_mock
@@ -77,7 +98,7 @@ where
                 board.push(managed_address!(&first_board_member));
                 board.push(managed_address!(&second_board_member));
 
-                sc.init(2, board);
+                sc.init(2, None, None, board);
             })
             .assert_ok();
 
@@ -93,6 +114,7 @@ where
             b_mock,
             first_board_member,
             second_board_member,
+            second_board_member_key,
             pass_wrapper,
             ms_owner,
             ms_wrapper,
@@ -125,8 +147,9 @@ where
                             managed_address!(to),
                             managed_biguint!(egld_amount),
                             None,
+                            None,
+                            None,
                             function_call,
-                            OptionalValue::None,
                         )
                         .into_option()
                         .unwrap();
@@ -156,8 +179,9 @@ where
                     managed_address!(to),
                     managed_biguint!(egld_amount),
                     None,
+                    None,
+                    None,
                     function_call,
-                    OptionalValue::None,
                 );
                 assert!(result.is_none());
             })
@@ -184,8 +208,9 @@ where
                     managed_address!(to),
                     managed_biguint!(egld_amount),
                     None,
+                    None,
+                    None,
                     function_call,
-                    OptionalValue::None,
                 );
                 assert!(result.is_none());
             })
@@ -221,6 +246,21 @@ where
     pub fn sign(&mut self, action_id: ActionId, signer_nonce: Nonce) {
         let signer_addr = self.second_board_member.clone();
 
+        let mut action_hash = [0u8; 32];
+        self.b_mock
+            .execute_query(&self.ms_wrapper, |sc| {
+                let action = sc.action_mapper().get(action_id);
+                action_hash = sc
+                    .serialize_and_hash_action(
+                        &action,
+                        &managed_address!(&signer_addr),
+                        signer_nonce,
+                    )
+                    .to_byte_array();
+            })
+            .assert_ok();
+        let signature = self.second_board_member_key.sign(&action_hash).to_bytes();
+
         self.b_mock
             .execute_tx(
                 &self.second_board_member,
@@ -232,8 +272,8 @@ where
                         user_address: managed_address!(&signer_addr),
                         nonce: signer_nonce,
                         action_type: ActionType::SimpleAction,
-                        raw_sig_bytes: managed_buffer!(b"signature"),
-                        signature_type: SignatureType::Ed25519, // unused
+                        raw_sig_bytes: managed_buffer!(&signature),
+                        signature_type: SignatureType::Ed25519,
                     });
 
                     sc.sign(action_id, signatures);
@@ -251,8 +291,11 @@ where
                 &self.ms_wrapper,
                 &rust_biguint!(0),
                 |sc| {
-                    action_id =
-                        sc.propose_add_module(managed_address!(sc_address), OptionalValue::None);
+                    action_id = sc.propose_add_module(
+                        managed_address!(sc_address),
+                        None,
+                        OptionalValue::None,
+                    );
                 },
             )
             .assert_ok();
@@ -0,0 +1,81 @@
+use super::storage::{ActionId, Allowance, PaymentsVec, Period, Usage};
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait AllowancesModule:
+    only_multisig::OnlyMultisig
+    + super::storage::AllowancesStorageModule
+    + super::views::AllowancesViewsModule
+{
+    /// For EGLD, simply pass "EGLD" as token ID.
+    /// Also resets the amount spent in the current period.
+    #[endpoint(setAllowance)]
+    fn set_allowance(
+        &self,
+        proposer: ManagedAddress,
+        token_id: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+        period: Period,
+    ) {
+        self.require_multisig_caller();
+        require!(token_id.is_valid(), "Invalid token ID");
+        require!(amount > 0, "Invalid amount");
+
+        self.allowance(&proposer, &token_id)
+            .set(Allowance { amount, period });
+        self.usage(&proposer, &token_id).clear();
+    }
+
+    #[endpoint(removeAllowance)]
+    fn remove_allowance(&self, proposer: ManagedAddress, token_id: EgldOrEsdtTokenIdentifier) {
+        self.require_multisig_caller();
+
+        self.allowance(&proposer, &token_id).clear();
+        self.usage(&proposer, &token_id).clear();
+    }
+
+    /// Called by the multisig after executing an action approved through `canExecute`.
+    /// Requires notifications to be enabled for this module in the multisig.
+    #[endpoint(onActionExecuted)]
+    fn on_action_executed(
+        &self,
+        _action_id: ActionId,
+        proposer: ManagedAddress,
+        _sc_address: ManagedAddress,
+        _endpoint_name: ManagedBuffer,
+        egld_value: BigUint,
+        esdt_payments: PaymentsVec<Self::Api>,
+    ) {
+        self.require_multisig_caller();
+
+        if egld_value > 0 {
+            self.consume_allowance(&proposer, &EgldOrEsdtTokenIdentifier::egld(), egld_value);
+        }
+
+        for payment in &esdt_payments {
+            self.consume_allowance(
+                &proposer,
+                &EgldOrEsdtTokenIdentifier::esdt(payment.token_identifier),
+                payment.amount,
+            );
+        }
+    }
+
+    fn consume_allowance(
+        &self,
+        proposer: &ManagedAddress,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+    ) {
+        let remaining = self.get_remaining_allowance(proposer.clone(), token_id.clone());
+        require!(amount <= remaining, "Allowance exceeded");
+
+        let allowance = self.allowance(proposer, token_id).get();
+        let spent = self.get_spent_in_current_period(proposer, token_id, allowance.period);
+        self.usage(proposer, token_id).set(Usage {
+            period_index: self.get_current_period_index(allowance.period),
+            spent: spent + amount,
+        });
+    }
+}
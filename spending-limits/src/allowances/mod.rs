@@ -0,0 +1,3 @@
+pub mod main;
+pub mod storage;
+pub mod views;
@@ -0,0 +1,54 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub type PaymentsVec<M> = ManagedVec<M, EsdtTokenPayment<M>>;
+pub type ActionId = usize;
+pub type Timestamp = u64;
+pub type PeriodIndex = u64;
+
+pub const SECONDS_PER_DAY: Timestamp = 24 * 60 * 60;
+pub const SECONDS_PER_WEEK: Timestamp = 7 * SECONDS_PER_DAY;
+
+/// Keys in the storage of the multisig, see its `moduleId` and `moduleNotifications` mappers
+pub const MULTISIG_MODULE_ID_KEY: &[u8] = b"moduleIdaddr";
+pub const MULTISIG_NOTIFICATIONS_KEY: &[u8] = b"moduleNotifications";
+
+/// Interval after which the spent amount is reset.
+/// Days and weeks are counted from the Unix epoch, based on the block timestamp.
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, Clone, Copy, PartialEq, Eq, Debug,
+)]
+pub enum Period {
+    Day,
+    Week,
+    Epoch,
+}
+
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, TypeAbi, PartialEq, Debug)]
+pub struct Allowance<M: ManagedTypeApi> {
+    pub amount: BigUint<M>,
+    pub period: Period,
+}
+
+#[derive(TopEncode, TopDecode)]
+pub struct Usage<M: ManagedTypeApi> {
+    pub period_index: PeriodIndex,
+    pub spent: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait AllowancesStorageModule {
+    #[storage_mapper("allowance")]
+    fn allowance(
+        &self,
+        proposer: &ManagedAddress,
+        token_id: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<Allowance<Self::Api>>;
+
+    #[storage_mapper("usage")]
+    fn usage(
+        &self,
+        proposer: &ManagedAddress,
+        token_id: &EgldOrEsdtTokenIdentifier,
+    ) -> SingleValueMapper<Usage<Self::Api>>;
+}
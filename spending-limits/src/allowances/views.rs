@@ -0,0 +1,157 @@
+use super::storage::{
+    Allowance, PaymentsVec, Period, PeriodIndex, MULTISIG_MODULE_ID_KEY,
+    MULTISIG_NOTIFICATIONS_KEY, SECONDS_PER_DAY, SECONDS_PER_WEEK,
+};
+use multiversx_sc::storage::StorageKey;
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait AllowancesViewsModule:
+    only_multisig::OnlyMultisig + super::storage::AllowancesStorageModule
+{
+    /// Approves transfers that fit in the remaining allowances of the proposer.
+    /// Actions that transfer no tokens are never approved.
+    /// Nothing is approved while the multisig does not notify this module after execution,
+    /// as the spent amounts could not be deducted.
+    #[view(canExecute)]
+    fn can_execute(
+        &self,
+        proposer: ManagedAddress,
+        _sc_address: ManagedAddress,
+        _endpoint_name: ManagedBuffer,
+        egld_value: BigUint,
+        esdt_payments: PaymentsVec<Self::Api>,
+    ) -> bool {
+        if egld_value == 0 && esdt_payments.is_empty() {
+            return false;
+        }
+
+        if !self.are_notifications_enabled() {
+            return false;
+        }
+
+        if egld_value > 0 {
+            let remaining =
+                self.get_remaining_allowance(proposer.clone(), EgldOrEsdtTokenIdentifier::egld());
+            if egld_value > remaining {
+                return false;
+            }
+        }
+
+        for payment in &esdt_payments {
+            if payment.token_nonce != 0 {
+                return false;
+            }
+
+            // the same token may be sent multiple times in a single transfer
+            let total_amount = self.get_total_amount(&esdt_payments, &payment.token_identifier);
+            let remaining = self.get_remaining_allowance(
+                proposer.clone(),
+                EgldOrEsdtTokenIdentifier::esdt(payment.token_identifier),
+            );
+            if total_amount > remaining {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Read directly from the multisig storage, as the multisig is the one calling this view.
+    #[view(areNotificationsEnabled)]
+    fn are_notifications_enabled(&self) -> bool {
+        let ms_address = self.multisig_address().get();
+        let mut module_id_key = StorageKey::new(MULTISIG_MODULE_ID_KEY);
+        module_id_key.append_item(&self.blockchain().get_sc_address());
+        let module_id: AddressId = self
+            .storage_raw()
+            .read_from_address(&ms_address, module_id_key);
+        if module_id == NULL_ID {
+            return false;
+        }
+
+        let mut notifications_key = StorageKey::new(MULTISIG_NOTIFICATIONS_KEY);
+        notifications_key.append_item(&module_id);
+
+        self.storage_raw()
+            .read_from_address(&ms_address, notifications_key)
+    }
+
+    #[view(getAllowance)]
+    fn get_allowance(
+        &self,
+        proposer: ManagedAddress,
+        token_id: EgldOrEsdtTokenIdentifier,
+    ) -> OptionalValue<Allowance<Self::Api>> {
+        let mapper = self.allowance(&proposer, &token_id);
+        if mapper.is_empty() {
+            return OptionalValue::None;
+        }
+
+        OptionalValue::Some(mapper.get())
+    }
+
+    /// Amount the proposer can still spend in the current period.
+    #[view(getRemainingAllowance)]
+    fn get_remaining_allowance(
+        &self,
+        proposer: ManagedAddress,
+        token_id: EgldOrEsdtTokenIdentifier,
+    ) -> BigUint {
+        let allowance_mapper = self.allowance(&proposer, &token_id);
+        if allowance_mapper.is_empty() {
+            return BigUint::zero();
+        }
+
+        let allowance = allowance_mapper.get();
+        let spent = self.get_spent_in_current_period(&proposer, &token_id, allowance.period);
+        if spent >= allowance.amount {
+            return BigUint::zero();
+        }
+
+        allowance.amount - spent
+    }
+
+    fn get_spent_in_current_period(
+        &self,
+        proposer: &ManagedAddress,
+        token_id: &EgldOrEsdtTokenIdentifier,
+        period: Period,
+    ) -> BigUint {
+        let usage_mapper = self.usage(proposer, token_id);
+        if usage_mapper.is_empty() {
+            return BigUint::zero();
+        }
+
+        let usage = usage_mapper.get();
+        if usage.period_index != self.get_current_period_index(period) {
+            return BigUint::zero();
+        }
+
+        usage.spent
+    }
+
+    fn get_current_period_index(&self, period: Period) -> PeriodIndex {
+        match period {
+            Period::Day => self.blockchain().get_block_timestamp() / SECONDS_PER_DAY,
+            Period::Week => self.blockchain().get_block_timestamp() / SECONDS_PER_WEEK,
+            Period::Epoch => self.blockchain().get_block_epoch(),
+        }
+    }
+
+    fn get_total_amount(
+        &self,
+        payments: &PaymentsVec<Self::Api>,
+        token_id: &TokenIdentifier,
+    ) -> BigUint {
+        let mut total_amount = BigUint::zero();
+        for payment in payments {
+            if &payment.token_identifier == token_id {
+                total_amount += payment.amount;
+            }
+        }
+
+        total_amount
+    }
+}
@@ -0,0 +1,23 @@
+#![no_std]
+
+multiversx_sc::imports!();
+
+pub mod allowances;
+
+#[multiversx_sc::contract]
+pub trait SpendingLimits:
+    only_multisig::OnlyMultisig
+    + allowances::main::AllowancesModule
+    + allowances::views::AllowancesViewsModule
+    + allowances::storage::AllowancesStorageModule
+{
+    #[init]
+    fn init(&self, multisig_address: ManagedAddress) {
+        self.require_sc_address(&multisig_address);
+
+        self.multisig_address().set(multisig_address);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+}
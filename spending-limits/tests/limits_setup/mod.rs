@@ -0,0 +1,375 @@
+use ed25519_dalek::{Signer, SigningKey};
+use multisig_improved::{
+    check_signature::CheckSignatureModule,
+    common_types::{
+        action::{ActionId, Nonce},
+        signature::{ActionType, SignatureArg, SignatureType},
+    },
+    ms_endpoints::{
+        perform::PerformEndpointsModule, propose::ProposeEndpointsModule, sign::SignEndpointsModule,
+    },
+    state::StateModule,
+    Multisig,
+};
+use multiversx_sc::{
+    codec::TopEncode,
+    imports::OptionalValue,
+    types::{Address, EsdtTokenPayment, FunctionCall, ManagedVec, MultiValueEncoded},
+};
+use multiversx_sc_scenario::{
+    imports::{BlockchainStateWrapper, ContractObjWrapper},
+    managed_address, managed_biguint, managed_buffer, managed_token_id, rust_biguint, DebugApi,
+};
+use spending_limits::{allowances::storage::Period, SpendingLimits};
+use test_utils::sc_address;
+
+fn top_encode<T: TopEncode>(value: &T) -> Vec<u8> {
+    let mut encoded = Vec::new();
+    let _ = value.top_encode(&mut encoded);
+
+    encoded
+}
+
+fn build_payments(payments: &[(&[u8], u64)]) -> ManagedVec<DebugApi, EsdtTokenPayment<DebugApi>> {
+    let mut result = ManagedVec::new();
+    for (token_id, amount) in payments {
+        result.push(EsdtTokenPayment::new(
+            managed_token_id!(*token_id),
+            0,
+            managed_biguint!(*amount),
+        ));
+    }
+
+    result
+}
+
+pub struct LimitsSetup<LimitsBuilder, MsImprovedBuilder>
+where
+    LimitsBuilder: 'static + Copy + Fn() -> spending_limits::ContractObj<DebugApi>,
+    MsImprovedBuilder: 'static + Copy + Fn() -> multisig_improved::ContractObj<DebugApi>,
+{
+    pub b_mock: BlockchainStateWrapper,
+    pub first_board_member: Address,
+    pub second_board_member: Address,
+    pub second_board_member_key: SigningKey,
+    pub ms_owner: Address,
+    pub limits_wrapper: ContractObjWrapper<spending_limits::ContractObj<DebugApi>, LimitsBuilder>,
+    pub ms_wrapper: ContractObjWrapper<multisig_improved::ContractObj<DebugApi>, MsImprovedBuilder>,
+}
+
+impl<LimitsBuilder, MsImprovedBuilder> LimitsSetup<LimitsBuilder, MsImprovedBuilder>
+where
+    LimitsBuilder: 'static + Copy + Fn() -> spending_limits::ContractObj<DebugApi>,
+    MsImprovedBuilder: 'static + Copy + Fn() -> multisig_improved::ContractObj<DebugApi>,
+{
+    pub fn new(limits_builder: LimitsBuilder, ms_builder: MsImprovedBuilder) -> Self {
+        let rust_zero = rust_biguint!(0u64);
+        let mut b_mock = BlockchainStateWrapper::new();
+        let first_board_member = b_mock.create_user_account(&rust_zero);
+
+        // the signing board member is an Ed25519 account, its address is its public key
+        let second_board_member_key = SigningKey::from_bytes(&[2u8; 32]);
+        let second_board_member = Address::from(second_board_member_key.verifying_key().to_bytes());
+        b_mock.create_user_account_fixed_address(&second_board_member, &rust_zero);
+
+        let ms_owner = b_mock.create_user_account(&rust_zero);
+        let ms_wrapper = b_mock.create_sc_account_fixed_address(
+            &sc_address(b"multisig"),
+            &rust_zero,
+            Some(&ms_owner),
+            ms_builder,
+            "multisig",
+        );
+        let limits_wrapper = b_mock.create_sc_account_fixed_address(
+            &sc_address(b"spending-limits"),
+            &rust_zero,
+            Some(&ms_owner),
+            limits_builder,
+            "spending-limits",
+        );
+
+        // init multisig
+        b_mock
+            .execute_tx(&ms_owner, &ms_wrapper, &rust_zero, |sc| {
+                let mut board = MultiValueEncoded::new();
+                board.push(managed_address!(&first_board_member));
+                board.push(managed_address!(&second_board_member));
+
+                sc.init(2, None, None, board);
+            })
+            .assert_ok();
+
+        // init spending limits
+        let ms_address = ms_wrapper.address_ref().clone();
+        b_mock
+            .execute_tx(&ms_owner, &limits_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&ms_address));
+            })
+            .assert_ok();
+
+        Self {
+            b_mock,
+            first_board_member,
+            second_board_member,
+            second_board_member_key,
+            ms_owner,
+            limits_wrapper,
+            ms_wrapper,
+        }
+    }
+
+    /// Uses the signer nonce 0.
+    pub fn add_module(&mut self) {
+        let module_address = self.limits_wrapper.address_ref().clone();
+        let action_id = self.propose_add_module(&module_address);
+        self.sign(action_id, 0);
+        self.perform(action_id);
+    }
+
+    /// Uses the signer nonces 0 and 1.
+    pub fn add_module_with_notifications(&mut self) {
+        self.add_module();
+
+        let module_address = self.limits_wrapper.address_ref().clone();
+        let action_id = self.propose_set_module_notifications(&module_address, true);
+        self.sign(action_id, 1);
+        self.perform(action_id);
+    }
+
+    pub fn propose_add_module(&mut self, sc_address: &Address) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    action_id = sc.propose_add_module(
+                        managed_address!(sc_address),
+                        None,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    pub fn propose_set_module_notifications(
+        &mut self,
+        sc_address: &Address,
+        enabled: bool,
+    ) -> ActionId {
+        let mut action_id = 0;
+
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    action_id = sc.propose_set_module_notifications(
+                        managed_address!(sc_address),
+                        enabled,
+                        None,
+                        OptionalValue::None,
+                    );
+                },
+            )
+            .assert_ok();
+
+        action_id
+    }
+
+    /// Proposes calling `setAllowance` on the module, signs and performs it.
+    pub fn set_allowance(
+        &mut self,
+        proposer: &Address,
+        token_id: &[u8],
+        amount: u64,
+        period: Period,
+        signer_nonce: Nonce,
+    ) {
+        let args = vec![
+            top_encode(proposer),
+            token_id.to_vec(),
+            rust_biguint!(amount).to_bytes_be(),
+            top_encode(&period),
+        ];
+        let module_address = self.limits_wrapper.address_ref().clone();
+
+        let mut action_id = 0;
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let mut function_call = FunctionCall::new(b"setAllowance");
+                    for arg in args {
+                        function_call = function_call.argument(&arg);
+                    }
+
+                    action_id = sc
+                        .propose_transfer_execute(
+                            managed_address!(&module_address),
+                            managed_biguint!(0),
+                            None,
+                            None,
+                            None,
+                            function_call,
+                        )
+                        .into_option()
+                        .unwrap();
+                },
+            )
+            .assert_ok();
+
+        self.sign(action_id, signer_nonce);
+        self.perform(action_id);
+    }
+
+    /// Returns the action ID if the transfer was not executed directly.
+    pub fn propose_egld_transfer(
+        &mut self,
+        from: &Address,
+        to: &Address,
+        egld_amount: u64,
+    ) -> Option<ActionId> {
+        let mut opt_action_id = None;
+
+        self.b_mock
+            .execute_tx(from, &self.ms_wrapper, &rust_biguint!(0), |sc| {
+                opt_action_id = sc
+                    .propose_transfer_execute(
+                        managed_address!(to),
+                        managed_biguint!(egld_amount),
+                        None,
+                        None,
+                        None,
+                        FunctionCall::empty(),
+                    )
+                    .into_option();
+            })
+            .assert_ok();
+
+        opt_action_id
+    }
+
+    pub fn propose_egld_transfer_expect_err(
+        &mut self,
+        from: &Address,
+        to: &Address,
+        egld_amount: u64,
+        err_message: &str,
+    ) {
+        self.b_mock
+            .execute_tx(from, &self.ms_wrapper, &rust_biguint!(0), |sc| {
+                let _ = sc.propose_transfer_execute(
+                    managed_address!(to),
+                    managed_biguint!(egld_amount),
+                    None,
+                    None,
+                    None,
+                    FunctionCall::empty(),
+                );
+            })
+            .assert_user_error(err_message);
+    }
+
+    pub fn propose_esdt_transfer(
+        &mut self,
+        from: &Address,
+        to: &Address,
+        payments: &[(&[u8], u64)],
+    ) {
+        self.b_mock
+            .execute_tx(from, &self.ms_wrapper, &rust_biguint!(0), |sc| {
+                let result = sc.propose_transfer_execute_esdt(
+                    managed_address!(to),
+                    build_payments(payments),
+                    None,
+                    None,
+                    None,
+                    FunctionCall::empty(),
+                );
+                assert!(result.is_none());
+            })
+            .assert_ok();
+    }
+
+    pub fn propose_esdt_transfer_expect_err(
+        &mut self,
+        from: &Address,
+        to: &Address,
+        payments: &[(&[u8], u64)],
+        err_message: &str,
+    ) {
+        self.b_mock
+            .execute_tx(from, &self.ms_wrapper, &rust_biguint!(0), |sc| {
+                let _ = sc.propose_transfer_execute_esdt(
+                    managed_address!(to),
+                    build_payments(payments),
+                    None,
+                    None,
+                    None,
+                    FunctionCall::empty(),
+                );
+            })
+            .assert_user_error(err_message);
+    }
+
+    pub fn perform(&mut self, action_id: ActionId) {
+        self.b_mock
+            .execute_tx(
+                &self.first_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let _ = sc.perform_action_endpoint(action_id);
+                },
+            )
+            .assert_ok();
+    }
+
+    pub fn sign(&mut self, action_id: ActionId, signer_nonce: Nonce) {
+        let signer_addr = self.second_board_member.clone();
+
+        let mut action_hash = [0u8; 32];
+        self.b_mock
+            .execute_query(&self.ms_wrapper, |sc| {
+                let action = sc.action_mapper().get(action_id);
+                action_hash = sc
+                    .serialize_and_hash_action(
+                        &action,
+                        &managed_address!(&signer_addr),
+                        signer_nonce,
+                    )
+                    .to_byte_array();
+            })
+            .assert_ok();
+        let signature = self.second_board_member_key.sign(&action_hash).to_bytes();
+
+        self.b_mock
+            .execute_tx(
+                &self.second_board_member,
+                &self.ms_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    let mut signatures = MultiValueEncoded::new();
+                    signatures.push(SignatureArg {
+                        user_address: managed_address!(&signer_addr),
+                        nonce: signer_nonce,
+                        action_type: ActionType::SimpleAction,
+                        raw_sig_bytes: managed_buffer!(&signature),
+                        signature_type: SignatureType::Ed25519,
+                    });
+
+                    sc.sign(action_id, signatures);
+                },
+            )
+            .assert_ok();
+    }
+}
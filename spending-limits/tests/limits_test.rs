@@ -0,0 +1,194 @@
+use limits_setup::LimitsSetup;
+use multiversx_sc::types::{EgldOrEsdtTokenIdentifier, ManagedBuffer, ManagedVec};
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint};
+use spending_limits::allowances::{
+    main::AllowancesModule, storage::Period, views::AllowancesViewsModule,
+};
+
+pub mod limits_setup;
+
+const TOKEN_ID: &[u8] = b"USDC-123456";
+const OTHER_TOKEN_ID: &[u8] = b"WEGLD-123456";
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+#[test]
+fn setup_test() {
+    let mut setup = LimitsSetup::new(
+        spending_limits::contract_obj,
+        multisig_improved::contract_obj,
+    );
+    setup.add_module_with_notifications();
+}
+
+#[test]
+fn egld_allowance_test() {
+    let mut setup = LimitsSetup::new(
+        spending_limits::contract_obj,
+        multisig_improved::contract_obj,
+    );
+    setup.add_module_with_notifications();
+
+    let ms_address = setup.ms_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .set_egld_balance(&ms_address, &rust_biguint!(1_000));
+
+    let spender = setup.b_mock.create_user_account(&rust_biguint!(0));
+    let receiver = setup.b_mock.create_user_account(&rust_biguint!(0));
+    setup.set_allowance(&spender, b"EGLD", 100, Period::Day, 2);
+
+    let opt_action_id = setup.propose_egld_transfer(&spender, &receiver, 60);
+    assert_eq!(opt_action_id, None);
+    setup
+        .b_mock
+        .check_egld_balance(&receiver, &rust_biguint!(60));
+
+    setup
+        .b_mock
+        .execute_query(&setup.limits_wrapper, |sc| {
+            let remaining = sc.get_remaining_allowance(
+                managed_address!(&spender),
+                EgldOrEsdtTokenIdentifier::egld(),
+            );
+            assert_eq!(remaining, managed_biguint!(40));
+        })
+        .assert_ok();
+
+    // over the remaining allowance, so the usual proposal rules apply
+    setup.propose_egld_transfer_expect_err(
+        &spender,
+        &receiver,
+        50,
+        "only board members and proposers can propose",
+    );
+
+    // the allowance resets the next day
+    setup.b_mock.set_block_timestamp(SECONDS_PER_DAY);
+    let opt_action_id = setup.propose_egld_transfer(&spender, &receiver, 50);
+    assert_eq!(opt_action_id, None);
+    setup
+        .b_mock
+        .check_egld_balance(&receiver, &rust_biguint!(110));
+
+    // other users have no allowance
+    let other_user = setup.b_mock.create_user_account(&rust_biguint!(0));
+    setup.propose_egld_transfer_expect_err(
+        &other_user,
+        &receiver,
+        1,
+        "only board members and proposers can propose",
+    );
+}
+
+#[test]
+fn esdt_allowance_test() {
+    let mut setup = LimitsSetup::new(
+        spending_limits::contract_obj,
+        multisig_improved::contract_obj,
+    );
+    setup.add_module_with_notifications();
+
+    let ms_address = setup.ms_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .set_esdt_balance(&ms_address, TOKEN_ID, &rust_biguint!(1_000));
+    setup
+        .b_mock
+        .set_esdt_balance(&ms_address, OTHER_TOKEN_ID, &rust_biguint!(1_000));
+
+    let spender = setup.b_mock.create_user_account(&rust_biguint!(0));
+    let receiver = setup.b_mock.create_user_account(&rust_biguint!(0));
+    setup.set_allowance(&spender, TOKEN_ID, 100, Period::Epoch, 2);
+
+    // payments of the same token are added up
+    setup.propose_esdt_transfer_expect_err(
+        &spender,
+        &receiver,
+        &[(TOKEN_ID, 60), (TOKEN_ID, 60)],
+        "only board members and proposers can propose",
+    );
+
+    setup.propose_esdt_transfer(&spender, &receiver, &[(TOKEN_ID, 30), (TOKEN_ID, 50)]);
+    setup
+        .b_mock
+        .check_esdt_balance(&receiver, TOKEN_ID, &rust_biguint!(80));
+
+    setup.propose_esdt_transfer_expect_err(
+        &spender,
+        &receiver,
+        &[(TOKEN_ID, 10), (OTHER_TOKEN_ID, 10)],
+        "only board members and proposers can propose",
+    );
+
+    setup.b_mock.set_block_epoch(1);
+    setup.propose_esdt_transfer(&spender, &receiver, &[(TOKEN_ID, 100)]);
+    setup
+        .b_mock
+        .check_esdt_balance(&receiver, TOKEN_ID, &rust_biguint!(180));
+}
+
+#[test]
+fn no_approval_without_notifications_test() {
+    let mut setup = LimitsSetup::new(
+        spending_limits::contract_obj,
+        multisig_improved::contract_obj,
+    );
+    setup.add_module();
+
+    let ms_address = setup.ms_wrapper.address_ref().clone();
+    setup
+        .b_mock
+        .set_egld_balance(&ms_address, &rust_biguint!(1_000));
+
+    let spender = setup.b_mock.create_user_account(&rust_biguint!(0));
+    let receiver = setup.b_mock.create_user_account(&rust_biguint!(0));
+    setup.set_allowance(&spender, b"EGLD", 100, Period::Day, 1);
+
+    // the spent amount would never be deducted
+    setup.propose_egld_transfer_expect_err(
+        &spender,
+        &receiver,
+        60,
+        "only board members and proposers can propose",
+    );
+    setup
+        .b_mock
+        .execute_query(&setup.limits_wrapper, |sc| {
+            assert!(!sc.are_notifications_enabled());
+        })
+        .assert_ok();
+
+    let module_address = setup.limits_wrapper.address_ref().clone();
+    let action_id = setup.propose_set_module_notifications(&module_address, true);
+    setup.sign(action_id, 2);
+    setup.perform(action_id);
+
+    let opt_action_id = setup.propose_egld_transfer(&spender, &receiver, 60);
+    assert_eq!(opt_action_id, None);
+    setup
+        .b_mock
+        .check_egld_balance(&receiver, &rust_biguint!(60));
+}
+
+#[test]
+fn only_multisig_can_report_execution_test() {
+    let mut setup = LimitsSetup::new(
+        spending_limits::contract_obj,
+        multisig_improved::contract_obj,
+    );
+
+    let spender = setup.b_mock.create_user_account(&rust_biguint!(0));
+    setup
+        .b_mock
+        .execute_tx(&spender, &setup.limits_wrapper, &rust_biguint!(0), |sc| {
+            sc.on_action_executed(
+                1,
+                managed_address!(&spender),
+                managed_address!(&spender),
+                ManagedBuffer::new(),
+                managed_biguint!(0),
+                ManagedVec::new(),
+            );
+        })
+        .assert_user_error("Only multisig may call this endpoint");
+}
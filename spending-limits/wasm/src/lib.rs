@@ -0,0 +1,34 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                            8
+// Async Callback (empty):               1
+// Total number of exported functions:  11
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    spending_limits
+    (
+        init => init
+        upgrade => upgrade
+        getMultisigAddress => multisig_address
+        setAllowance => set_allowance
+        removeAllowance => remove_allowance
+        onActionExecuted => on_action_executed
+        canExecute => can_execute
+        areNotificationsEnabled => are_notifications_enabled
+        getAllowance => get_allowance
+        getRemainingAllowance => get_remaining_allowance
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback_empty! {}